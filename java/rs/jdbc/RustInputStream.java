@@ -0,0 +1,47 @@
+package rs.jdbc;
+
+import java.io.IOException;
+import java.io.InputStream;
+
+/**
+ * An InputStream pulling from a Rust std::io::Read, see src/wrapper/sql/stream.rs.
+ *
+ * The reader is owned by the Rust statement, which detaches it before freeing it.
+ *
+ * Rebuild the embedded class with: javac --release 8 java/rs/jdbc/RustInputStream.java
+ */
+final class RustInputStream extends InputStream {
+    private long handle;
+
+    RustInputStream(long handle) {
+        this.handle = handle;
+    }
+
+    private static native int read0(long handle, byte[] buffer, int offset, int length)
+            throws IOException;
+
+    @Override
+    public synchronized int read() throws IOException {
+        byte[] buffer = new byte[1];
+        int read = read(buffer, 0, 1);
+        return read == -1 ? -1 : buffer[0] & 0xff;
+    }
+
+    @Override
+    public synchronized int read(byte[] buffer, int offset, int length) throws IOException {
+        if (offset < 0 || length < 0 || length > buffer.length - offset) {
+            throw new IndexOutOfBoundsException();
+        }
+        if (handle == 0) {
+            throw new IOException("Stream closed");
+        }
+        if (length == 0) {
+            return 0;
+        }
+        return read0(handle, buffer, offset, length);
+    }
+
+    synchronized void detach() {
+        handle = 0;
+    }
+}
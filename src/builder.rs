@@ -1,4 +1,4 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
 pub use jni::JNIVersion;
 use jni::{
@@ -11,6 +11,7 @@ use crate::{
     wrapper::{
         hikari::{HikariConfig, HikariDataSource},
         properties::Properties,
+        sql::{FetchDirection, StatementOptions},
     },
     Datasource,
 };
@@ -23,6 +24,7 @@ pub struct Builder {
     pool_type: PoolType,
     factory: Option<FactoryFn>,
     vm: Option<Arc<JavaVM>>,
+    statement_options: StatementOptions,
 }
 
 pub enum PoolType {
@@ -51,6 +53,7 @@ impl Builder {
             pool_type: PoolType::HikariCP,
             factory: None,
             vm: None,
+            statement_options: StatementOptions::default(),
         }
     }
 
@@ -97,6 +100,30 @@ impl Builder {
         self
     }
 
+    /// Default query timeout of every statement.
+    pub fn query_timeout(mut self, timeout: Duration) -> Self {
+        self.statement_options.query_timeout = Some(timeout);
+        self
+    }
+
+    /// Default fetch size of every statement.
+    pub fn fetch_size(mut self, rows: i32) -> Self {
+        self.statement_options.fetch_size = Some(rows);
+        self
+    }
+
+    /// Default max rows of every statement.
+    pub fn max_rows(mut self, max: i64) -> Self {
+        self.statement_options.max_rows = Some(max);
+        self
+    }
+
+    /// Default fetch direction of every statement.
+    pub fn fetch_direction(mut self, direction: FetchDirection) -> Self {
+        self.statement_options.fetch_direction = Some(direction);
+        self
+    }
+
     pub fn build(self) -> Result<Datasource, InitError> {
         let vm = {
             if let Some(vm) = self.vm {
@@ -121,7 +148,7 @@ impl Builder {
         {
             check_datasource(&vm, &datasource)?;
         }
-        Ok(Datasource::new(vm, datasource))
+        Ok(Datasource::new(vm, datasource).statement_options(self.statement_options))
     }
 }
 
@@ -3,6 +3,15 @@ use std::fmt::Display;
 #[derive(Debug)]
 pub enum Error {
     JniError(jni::errors::Error),
+    Sql(SqlException),
+    /// A named parameter that does not appear in the statement.
+    UnknownParameter(String),
+    /// A query expected to return a row returned none.
+    NoRows,
+    /// A SQL `NULL` read into a non `Option` type, holds the column.
+    UnexpectedNull(String),
+    /// A value that cannot be converted to or from its SQL representation.
+    Conversion(Box<dyn std::error::Error + Send + Sync>),
     ImpossibleError,
 }
 
@@ -12,15 +21,222 @@ impl From<jni::errors::Error> for Error {
     }
 }
 
+impl Error {
+    /// Classifies the error, returns [`ErrorKind::Other`] for non SQL errors.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Sql(err) => err.kind,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Error::JniError(err) => err.fmt(f),
+            Error::Sql(err) => err.fmt(f),
+            Error::UnknownParameter(name) => write!(f, "Unknown parameter: {name}."),
+            Error::NoRows => f.write_str("Query returned no rows."),
+            Error::UnexpectedNull(column) => write!(f, "Unexpected NULL in column {column}."),
+            Error::Conversion(err) => write!(f, "Conversion error: {err}"),
             Error::ImpossibleError => f.write_str("Impossible Error."),
         }
     }
 }
 
+/// Details of a `java.lang.Throwable` thrown by a JDBC call.
+///
+/// `sql_state`, `error_code` and `next` are only filled for `java.sql.SQLException`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlException {
+    /// Fully qualified name of the exception class, e.g. `org.sqlite.SQLiteException`.
+    pub class_name: String,
+    /// `getMessage()`
+    pub message: Option<String>,
+    /// `SQLException.getSQLState()`
+    pub sql_state: Option<String>,
+    /// `SQLException.getErrorCode()`, vendor specific.
+    pub error_code: i32,
+    /// Classified from the SQLState, the `java.sql` exception subclass and the vendor code.
+    pub kind: ErrorKind,
+    /// `BatchUpdateException.getLargeUpdateCounts()`, the update counts of the
+    /// commands executed before the failure.
+    pub update_counts: Option<Vec<i64>>,
+    /// `SQLException.getNextException()`
+    pub next: Option<Box<SqlException>>,
+    /// `Throwable.getCause()`
+    pub cause: Option<Box<SqlException>>,
+}
+
+impl SqlException {
+    /// Iterates over this exception and every chained `next` and `cause` exception.
+    pub fn chain(&self) -> impl Iterator<Item = &SqlException> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let current = stack.pop()?;
+            if let Some(cause) = &current.cause {
+                stack.push(cause);
+            }
+            if let Some(next) = &current.next {
+                stack.push(next);
+            }
+            Some(current)
+        })
+    }
+}
+
+impl Display for SqlException {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, err) in self.chain().enumerate() {
+            if i > 0 {
+                f.write_str("\nCaused by: ")?;
+            }
+            f.write_str(&err.class_name)?;
+            if let Some(message) = &err.message {
+                write!(f, ": {}", message)?;
+            }
+            if let Some(sql_state) = &err.sql_state {
+                write!(
+                    f,
+                    " (SQLState: {}, error code: {})",
+                    sql_state, err.error_code
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Portable classification of a [`SqlException`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    UniqueViolation,
+    ForeignKeyViolation,
+    NotNullViolation,
+    CheckViolation,
+    /// Any other integrity constraint violation, SQLState class `23`.
+    IntegrityConstraintViolation,
+    Deadlock,
+    SerializationFailure,
+    /// Any other transaction rollback, SQLState class `40`.
+    TransactionRollback,
+    Timeout,
+    ConnectionLost,
+    SyntaxError,
+    DataError,
+    FeatureNotSupported,
+    InvalidAuthorization,
+    /// `SQLTransientException` that matches no other kind.
+    Transient,
+    Other,
+}
+
+impl ErrorKind {
+    /// Whether retrying the same operation may succeed.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            ErrorKind::Deadlock
+                | ErrorKind::SerializationFailure
+                | ErrorKind::TransactionRollback
+                | ErrorKind::Timeout
+                | ErrorKind::ConnectionLost
+                | ErrorKind::Transient
+        )
+    }
+
+    /// `subclass` is the kind derived from the `java.sql` exception subclass.
+    pub(crate) fn classify(
+        class_name: &str,
+        subclass: ErrorKind,
+        sql_state: Option<&str>,
+        error_code: i32,
+        message: Option<&str>,
+    ) -> ErrorKind {
+        let kind = sql_state.map_or(ErrorKind::Other, |state| {
+            ErrorKind::from_sql_state(state, error_code)
+        });
+        // HikariCP reports a pool acquire timeout as a plain SQLTransientConnectionException,
+        // drivers throw their own subclasses. It carries the SQLState of the last connection
+        // failure if there was one, only then its message tells the timeout apart.
+        if class_name == "java.sql.SQLTransientConnectionException"
+            && (kind == ErrorKind::Other
+                || message.is_some_and(|message| message.contains("request timed out")))
+        {
+            return ErrorKind::Timeout;
+        }
+        let kind = match kind {
+            ErrorKind::Other => subclass,
+            kind => kind,
+        };
+        match (kind, message) {
+            (ErrorKind::Other | ErrorKind::IntegrityConstraintViolation, Some(message)) => {
+                ErrorKind::from_sqlite_message(message).unwrap_or(kind)
+            }
+            _ => kind,
+        }
+    }
+
+    fn from_sql_state(state: &str, error_code: i32) -> ErrorKind {
+        match state {
+            "23505" => return ErrorKind::UniqueViolation,
+            // H2 uses 23506 for a missing parent row.
+            "23503" | "23506" => return ErrorKind::ForeignKeyViolation,
+            "23502" => return ErrorKind::NotNullViolation,
+            // H2 uses 23513 for check constraints.
+            "23513" | "23514" => return ErrorKind::CheckViolation,
+            // MySQL, Oracle and SQL Server report every constraint as 23000.
+            "23000" => {
+                return match error_code {
+                    1 | 1062 | 1586 | 2601 | 2627 => ErrorKind::UniqueViolation,
+                    547 | 1216 | 1217 | 1451 | 1452 | 2291 | 2292 => ErrorKind::ForeignKeyViolation,
+                    515 | 1048 | 1400 => ErrorKind::NotNullViolation,
+                    2290 => ErrorKind::CheckViolation,
+                    _ => ErrorKind::IntegrityConstraintViolation,
+                }
+            }
+            "40P01" => return ErrorKind::Deadlock,
+            // MySQL, SQL Server and H2 report deadlocks as 40001.
+            "40001" => {
+                return match error_code {
+                    1205 | 1213 | 40001 => ErrorKind::Deadlock,
+                    _ => ErrorKind::SerializationFailure,
+                }
+            }
+            "57014" | "HYT00" | "HYT01" => return ErrorKind::Timeout,
+            _ => {}
+        }
+        match state.get(0..2) {
+            Some("23") => ErrorKind::IntegrityConstraintViolation,
+            Some("40") => ErrorKind::TransactionRollback,
+            Some("08") => ErrorKind::ConnectionLost,
+            Some("42") | Some("37") => ErrorKind::SyntaxError,
+            Some("22") => ErrorKind::DataError,
+            Some("0A") => ErrorKind::FeatureNotSupported,
+            Some("28") => ErrorKind::InvalidAuthorization,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    /// sqlite-jdbc reports no SQLState, only the primary result code,
+    /// the extended result code is the prefix of the message.
+    fn from_sqlite_message(message: &str) -> Option<ErrorKind> {
+        let code = message.strip_prefix("[SQLITE_")?;
+        let code = &code[..code.find(']')?];
+        let kind = match code {
+            "CONSTRAINT_UNIQUE" | "CONSTRAINT_PRIMARYKEY" => ErrorKind::UniqueViolation,
+            "CONSTRAINT_FOREIGNKEY" => ErrorKind::ForeignKeyViolation,
+            "CONSTRAINT_NOTNULL" => ErrorKind::NotNullViolation,
+            "CONSTRAINT_CHECK" => ErrorKind::CheckViolation,
+            "ERROR" if message.contains("syntax error") => ErrorKind::SyntaxError,
+            code if code.starts_with("CONSTRAINT") => ErrorKind::IntegrityConstraintViolation,
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
 #[derive(Debug)]
 pub enum InitError {
     JvmError(jni::JvmError),
@@ -15,20 +15,32 @@ use wrapper::sql;
 pub struct Datasource {
     vm: Arc<JavaVM>,
     inner: GlobalRef,
+    statement_options: sql::StatementOptions,
 }
 
-pub use wrapper::sql::Connection;
+pub use wrapper::sql::{Connection, FromSql, IsolationLevel, ToSql, Transaction};
 
 impl Datasource {
     pub fn new(vm: Arc<JavaVM>, inner: GlobalRef) -> Self {
-        Datasource { vm, inner }
+        Datasource {
+            vm,
+            inner,
+            statement_options: sql::StatementOptions::default(),
+        }
+    }
+
+    /// Defaults applied to every statement created from this datasource.
+    pub fn statement_options(mut self, options: sql::StatementOptions) -> Self {
+        self.statement_options = options;
+        self
     }
 
     pub fn get_connection(&self) -> Result<sql::Connection, Error> {
         let mut env = self.vm.attach_current_thread()?;
         let ds_ref = &*self.inner;
         let mut datasource = sql::DataSource::from_ref(&mut env, ds_ref)?;
-        let conn = datasource.get_connection(env)?;
+        let mut conn = datasource.get_connection(env)?;
+        conn.set_statement_options(self.statement_options);
         Ok(conn)
     }
 }
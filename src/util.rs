@@ -23,7 +23,7 @@ pub fn auto_close<'a>(env: &mut JNIEnv<'a>, obj: &JObject<'a>) -> Result<(), Err
     let method = env.get_method_id(autoclose, "close", "()V")?;
 
     let data = unsafe { env.call_method_unchecked(obj, method, ReturnType::Object, &[]) };
-    if let Err(err) = data {
+    if let Err(err) = exception::catch(env, data) {
         error!("Resource closing failed. {}", err);
     }
     Ok(())
@@ -33,7 +33,7 @@ pub fn auto_close<'a>(env: &mut JNIEnv<'a>, obj: &JObject<'a>) -> Result<(), Err
 pub fn get_class_name<'a>(env: &mut JNIEnv<'a>, obj: &JObject<'a>) -> Result<String, Error> {
     let obj_class = JObject::from(env.get_object_class(obj)?);
     let class = env.find_class("java/lang/Class")?;
-    let method = env.get_method_id(&class, "getName", "()Ljava.lang.String;")?;
+    let method = env.get_method_id(&class, "getName", "()Ljava/lang/String;")?;
 
     let name = unsafe { env.call_method_unchecked(&obj_class, method, ReturnType::Object, &[])? };
 
@@ -51,7 +51,7 @@ pub mod cast {
     use jni::signature::{Primitive, ReturnType};
     use jni::sys::{jvalue, JNI_TRUE};
     use jni::{
-        objects::{JObject, JString, JValueGen},
+        objects::{JByteArray, JIntArray, JLongArray, JObject, JString, JValueGen},
         JNIEnv,
     };
 
@@ -111,6 +111,7 @@ pub mod cast {
     use crate::value_cast;
     value_cast!(JValueGen::Char, u16, value_cast_char);
     value_cast!(JValueGen::Bool, bool, value_cast_bool);
+    value_cast!(JValueGen::Byte, i8, value_cast_i8);
     value_cast!(JValueGen::Short, i16, value_cast_i16);
     value_cast!(JValueGen::Int, i32, value_cast_i32);
     value_cast!(JValueGen::Long, i64, value_cast_i64);
@@ -144,17 +145,54 @@ pub mod cast {
         env.delete_local_ref(name)?;
         Ok(string)
     }
+
+    pub fn value_cast_bytes<'a>(
+        env: &mut JNIEnv<'a>,
+        obj: JValueGen<JObject<'a>>,
+    ) -> Result<Vec<u8>, Error> {
+        if let JValueGen::Object(obj) = obj {
+            if obj.is_null() {
+                return Err(Error::NullPtr("java.lang.NullPointerException"));
+            }
+            let array = JByteArray::from(obj);
+            let bytes = env.convert_byte_array(&array)?;
+            env.delete_local_ref(array)?;
+            return Ok(bytes);
+        }
+        Err(Error::JavaException)
+    }
+
+    pub fn obj_cast_i32_vec<'a>(env: &mut JNIEnv<'a>, obj: JObject<'a>) -> Result<Vec<i32>, Error> {
+        let array = JIntArray::from(obj);
+        let len = env.get_array_length(&array)?;
+        let mut buf = vec![0; len as usize];
+        env.get_int_array_region(&array, 0, &mut buf)?;
+        env.delete_local_ref(array)?;
+        Ok(buf)
+    }
+
+    pub fn obj_cast_i64_vec<'a>(env: &mut JNIEnv<'a>, obj: JObject<'a>) -> Result<Vec<i64>, Error> {
+        let array = JLongArray::from(obj);
+        let len = env.get_array_length(&array)?;
+        let mut buf = vec![0; len as usize];
+        env.get_long_array_region(&array, 0, &mut buf)?;
+        env.delete_local_ref(array)?;
+        Ok(buf)
+    }
 }
 
 pub mod call {
     use jni::{
         objects::{JMethodID, JObject, JValueGen},
         signature::{Primitive, ReturnType},
+        sys::jvalue,
         JNIEnv,
     };
 
     use crate::errors::Error;
 
+    use super::exception;
+
     #[inline(always)]
     pub fn get_int<'a>(
         env: &mut JNIEnv<'a>,
@@ -162,8 +200,9 @@ pub mod call {
         method: &JMethodID,
     ) -> Result<i32, Error> {
         let int = unsafe {
-            env.call_method_unchecked(obj, method, ReturnType::Primitive(Primitive::Int), &[])?
+            env.call_method_unchecked(obj, method, ReturnType::Primitive(Primitive::Int), &[])
         };
+        let int = exception::catch(env, int)?;
         if let JValueGen::Int(count) = int {
             return Ok(count);
         }
@@ -177,11 +216,794 @@ pub mod call {
         method: &JMethodID,
     ) -> Result<bool, Error> {
         let bool = unsafe {
-            env.call_method_unchecked(obj, method, ReturnType::Primitive(Primitive::Boolean), &[])?
+            env.call_method_unchecked(obj, method, ReturnType::Primitive(Primitive::Boolean), &[])
         };
+        let bool = exception::catch(env, bool)?;
         if let JValueGen::Bool(bool) = bool {
             return Ok(bool > 0);
         }
         return Err(Error::ImpossibleError);
     }
+
+    #[inline(always)]
+    pub fn void<'a>(
+        env: &mut JNIEnv<'a>,
+        obj: &JObject<'a>,
+        method: &JMethodID,
+        args: &[jvalue],
+    ) -> Result<(), Error> {
+        let result = unsafe {
+            env.call_method_unchecked(obj, method, ReturnType::Primitive(Primitive::Void), args)
+        };
+        exception::catch(env, result)?;
+        Ok(())
+    }
+}
+
+pub mod exception {
+    use jni::{
+        objects::{AutoLocal, JObject},
+        JNIEnv,
+    };
+
+    use crate::errors::{Error, ErrorKind, SqlException};
+
+    /// Guards against cyclic `getCause()`/`getNextException()` chains.
+    const MAX_DEPTH: usize = 16;
+
+    /// Most specific first, a driver exception usually extends one of these.
+    const SUBCLASSES: [(&str, ErrorKind); 11] = [
+        (
+            "java/sql/SQLIntegrityConstraintViolationException",
+            ErrorKind::IntegrityConstraintViolation,
+        ),
+        (
+            "java/sql/SQLTransactionRollbackException",
+            ErrorKind::TransactionRollback,
+        ),
+        ("java/sql/SQLTimeoutException", ErrorKind::Timeout),
+        (
+            "java/sql/SQLTransientConnectionException",
+            ErrorKind::ConnectionLost,
+        ),
+        (
+            "java/sql/SQLNonTransientConnectionException",
+            ErrorKind::ConnectionLost,
+        ),
+        (
+            "java/sql/SQLRecoverableException",
+            ErrorKind::ConnectionLost,
+        ),
+        ("java/sql/SQLSyntaxErrorException", ErrorKind::SyntaxError),
+        ("java/sql/SQLDataException", ErrorKind::DataError),
+        (
+            "java/sql/SQLFeatureNotSupportedException",
+            ErrorKind::FeatureNotSupported,
+        ),
+        (
+            "java/sql/SQLInvalidAuthorizationSpecException",
+            ErrorKind::InvalidAuthorization,
+        ),
+        ("java/sql/SQLTransientException", ErrorKind::Transient),
+    ];
+
+    /// Converts a pending Java exception into [`Error::Sql`] and clears it.
+    pub fn catch<'a, T>(
+        env: &mut JNIEnv<'a>,
+        result: Result<T, jni::errors::Error>,
+    ) -> Result<T, Error> {
+        match result {
+            Ok(value) => Ok(value),
+            Err(jni::errors::Error::JavaException) => Err(take(env)),
+            Err(err) => Err(Error::from(err)),
+        }
+    }
+
+    /// Takes the pending Java exception, clears it and reads its details.
+    pub fn take<'a>(env: &mut JNIEnv<'a>) -> Error {
+        let throwable = match env.exception_occurred() {
+            Ok(throwable) if !throwable.is_null() => throwable,
+            _ => return Error::JniError(jni::errors::Error::JavaException),
+        };
+        let _ = env.exception_clear();
+        let throwable = AutoLocal::new(JObject::from(throwable), env);
+        match read(env, &throwable, 0) {
+            Ok(exception) => Error::Sql(exception),
+            Err(err) => {
+                let _ = env.exception_clear();
+                Error::from(err)
+            }
+        }
+    }
+
+    fn read<'a>(
+        env: &mut JNIEnv<'a>,
+        throwable: &JObject<'a>,
+        depth: usize,
+    ) -> Result<SqlException, jni::errors::Error> {
+        let class_name = super::get_class_name(env, throwable)?;
+        let message = call_string(env, throwable, "getMessage")?;
+
+        let mut exception = SqlException {
+            class_name,
+            message,
+            sql_state: None,
+            error_code: 0,
+            kind: ErrorKind::Other,
+            update_counts: None,
+            next: None,
+            cause: None,
+        };
+
+        if env.is_instance_of(throwable, "java/sql/SQLException")? {
+            let mut subclass = ErrorKind::Other;
+            for (class, kind) in SUBCLASSES {
+                if env.is_instance_of(throwable, class)? {
+                    subclass = kind;
+                    break;
+                }
+            }
+
+            exception.sql_state = call_string(env, throwable, "getSQLState")?;
+            exception.error_code = env
+                .call_method(throwable, "getErrorCode", "()I", &[])?
+                .i()?;
+            if depth < MAX_DEPTH {
+                let next = env
+                    .call_method(
+                        throwable,
+                        "getNextException",
+                        "()Ljava/sql/SQLException;",
+                        &[],
+                    )?
+                    .l()?;
+                exception.next = read_chained(env, throwable, next, depth)?;
+            }
+            if env.is_instance_of(throwable, "java/sql/BatchUpdateException")? {
+                let counts = env
+                    .call_method(throwable, "getLargeUpdateCounts", "()[J", &[])?
+                    .l()?;
+                if !counts.is_null() {
+                    exception.update_counts = Some(super::cast::obj_cast_i64_vec(env, counts)?);
+                }
+            }
+            exception.kind = ErrorKind::classify(
+                &exception.class_name,
+                subclass,
+                exception.sql_state.as_deref(),
+                exception.error_code,
+                exception.message.as_deref(),
+            );
+        }
+
+        if depth < MAX_DEPTH {
+            let cause = env
+                .call_method(throwable, "getCause", "()Ljava/lang/Throwable;", &[])?
+                .l()?;
+            exception.cause = read_chained(env, throwable, cause, depth)?;
+        }
+
+        // e.g. BatchUpdateException, the reason is in the chained exceptions.
+        if exception.kind == ErrorKind::Other {
+            let chained = [&exception.next, &exception.cause];
+            if let Some(kind) = chained
+                .into_iter()
+                .flatten()
+                .map(|chained| chained.kind)
+                .find(|kind| *kind != ErrorKind::Other)
+            {
+                exception.kind = kind;
+            }
+        }
+        Ok(exception)
+    }
+
+    fn read_chained<'a>(
+        env: &mut JNIEnv<'a>,
+        throwable: &JObject<'a>,
+        chained: JObject<'a>,
+        depth: usize,
+    ) -> Result<Option<Box<SqlException>>, jni::errors::Error> {
+        let chained = AutoLocal::new(chained, env);
+        if chained.is_null() || env.is_same_object(throwable, &chained)? {
+            return Ok(None);
+        }
+        Ok(Some(Box::new(read(env, &chained, depth + 1)?)))
+    }
+
+    fn call_string<'a>(
+        env: &mut JNIEnv<'a>,
+        obj: &JObject<'a>,
+        name: &str,
+    ) -> Result<Option<String>, jni::errors::Error> {
+        let value = env
+            .call_method(obj, name, "()Ljava/lang/String;", &[])?
+            .l()?;
+        if value.is_null() {
+            return Ok(None);
+        }
+        super::cast::obj_cast_string(env, value).map(Some)
+    }
+}
+
+/// `java.sql.Blob` and `java.sql.Clob` contents.
+pub mod lob {
+    use jni::{
+        objects::{AutoLocal, JByteArray, JCharArray, JObject, JString, JValueGen},
+        JNIEnv,
+    };
+
+    use crate::errors::Error;
+
+    use super::exception;
+
+    /// Reads the whole `Blob` and frees it.
+    pub fn blob_to_bytes<'a>(env: &mut JNIEnv<'a>, blob: &JObject<'a>) -> Result<Vec<u8>, Error> {
+        let length = env.call_method(blob, "length", "()J", &[]);
+        let length = exception::catch(env, length)?.j()?;
+        let length = i32::try_from(length).map_err(|_| {
+            Error::Conversion(format!("Blob of {length} bytes is too large").into())
+        })?;
+        let bytes = env.call_method(
+            blob,
+            "getBytes",
+            "(JI)[B",
+            &[JValueGen::Long(1), JValueGen::Int(length)],
+        );
+        let bytes = AutoLocal::new(JByteArray::from(exception::catch(env, bytes)?.l()?), env);
+        let bytes = env.convert_byte_array(&bytes)?;
+        free(env, blob);
+        Ok(bytes)
+    }
+
+    /// Characters read per `Clob.getSubString` call.
+    const CLOB_CHUNK: i32 = 32 * 1024;
+
+    /// Reads the whole `Clob`/`NClob` in chunks of `getSubString` and frees it.
+    pub fn clob_to_string<'a>(env: &mut JNIEnv<'a>, clob: &JObject<'a>) -> Result<String, Error> {
+        let length = env.call_method(clob, "length", "()J", &[]);
+        let length = exception::catch(env, length)?.j()?;
+        let mut text = String::with_capacity(usize::try_from(length).unwrap_or(0));
+        let mut position = 1;
+        // High surrogate ending the previous chunk.
+        let mut surrogate = None;
+        while position <= length {
+            let count = (length - position + 1).min(CLOB_CHUNK as i64) as i32;
+            let chunk = sub_string(env, clob, position, count)?;
+            let chars = env.call_method(&chunk, "toCharArray", "()[C", &[]);
+            let chars = AutoLocal::new(JCharArray::from(exception::catch(env, chars)?.l()?), env);
+            let mut units: Vec<u16> = surrogate.take().into_iter().collect();
+            let start = units.len();
+            units.resize(start + env.get_array_length(&*chars)? as usize, 0);
+            env.get_char_array_region(&*chars, 0, &mut units[start..])?;
+            // Leave a surrogate pair split by the chunk to the next one.
+            if let Some(&last) = units.last() {
+                if (0xD800..=0xDBFF).contains(&last) {
+                    surrogate = units.pop();
+                }
+            }
+            text.push_str(&String::from_utf16_lossy(&units));
+            position += count as i64;
+        }
+        // A dangling high surrogate decodes to U+FFFD.
+        if let Some(surrogate) = surrogate {
+            text.push_str(&String::from_utf16_lossy(&[surrogate]));
+        }
+        free(env, clob);
+        Ok(text)
+    }
+
+    fn sub_string<'a>(
+        env: &mut JNIEnv<'a>,
+        clob: &JObject<'a>,
+        position: i64,
+        count: i32,
+    ) -> Result<AutoLocal<'a, JString<'a>>, Error> {
+        let args = [JValueGen::Long(position), JValueGen::Int(count)];
+        let chunk = env.call_method(clob, "getSubString", "(JI)Ljava/lang/String;", &args);
+        let chunk = JString::from(exception::catch(env, chunk)?.l()?);
+        if chunk.is_null() {
+            return Err(Error::ImpossibleError);
+        }
+        Ok(AutoLocal::new(chunk, env))
+    }
+
+    /// `Blob.free()`/`Clob.free()`, drivers without support keep it until the result set closes.
+    pub fn free<'a>(env: &mut JNIEnv<'a>, lob: &JObject<'a>) {
+        let result = env.call_method(lob, "free", "()V", &[]);
+        if let Err(err) = exception::catch(env, result) {
+            log::debug!("Freeing LOB failed. {}", err);
+        }
+    }
+
+    /// `new StringReader(text)` and its length in UTF-16 units.
+    pub fn string_reader<'a>(
+        env: &mut JNIEnv<'a>,
+        text: &str,
+    ) -> Result<(JObject<'a>, i64), Error> {
+        let string = AutoLocal::new(env.new_string(text)?, env);
+        let reader = env.new_object(
+            "java/io/StringReader",
+            "(Ljava/lang/String;)V",
+            &[(&string).into()],
+        );
+        let reader = exception::catch(env, reader)?;
+        Ok((reader, text.encode_utf16().count() as i64))
+    }
+
+    /// `new ByteArrayInputStream(bytes)`
+    pub fn input_stream<'a>(env: &mut JNIEnv<'a>, bytes: &[u8]) -> Result<JObject<'a>, Error> {
+        let array = AutoLocal::new(env.byte_array_from_slice(bytes)?, env);
+        let stream = env.new_object("java/io/ByteArrayInputStream", "([B)V", &[(&array).into()]);
+        exception::catch(env, stream)
+    }
+}
+
+/// `java.math.BigDecimal` <-> `rust_decimal::Decimal`, through the unscaled value bytes
+/// and the scale.
+#[cfg(feature = "rust_decimal")]
+pub mod decimal {
+    use jni::{
+        objects::{AutoLocal, JByteArray, JObject, JValueGen},
+        JNIEnv,
+    };
+    use rust_decimal::Decimal;
+
+    use crate::errors::Error;
+
+    use super::exception;
+
+    const MAX_SCALE: i64 = 28;
+    const MAX_MANTISSA: u128 = (1 << 96) - 1;
+
+    pub fn from_java<'a>(env: &mut JNIEnv<'a>, obj: &JObject<'a>) -> Result<Decimal, Error> {
+        let scale = env.call_method(obj, "scale", "()I", &[]);
+        let scale = exception::catch(env, scale)?.i()?;
+        let unscaled = env.call_method(obj, "unscaledValue", "()Ljava/math/BigInteger;", &[]);
+        let unscaled = AutoLocal::new(exception::catch(env, unscaled)?.l()?, env);
+        let bytes = env.call_method(&unscaled, "toByteArray", "()[B", &[]);
+        let bytes = AutoLocal::new(JByteArray::from(exception::catch(env, bytes)?.l()?), env);
+        let bytes = env.convert_byte_array(&bytes)?;
+        to_decimal(&bytes, scale)
+    }
+
+    pub fn to_java<'a>(env: &mut JNIEnv<'a>, value: &Decimal) -> Result<JObject<'a>, Error> {
+        let bytes = AutoLocal::new(
+            env.byte_array_from_slice(&value.mantissa().to_be_bytes())?,
+            env,
+        );
+        let unscaled = env.new_object("java/math/BigInteger", "([B)V", &[(&bytes).into()]);
+        let unscaled = AutoLocal::new(exception::catch(env, unscaled)?, env);
+        let decimal = env.new_object(
+            "java/math/BigDecimal",
+            "(Ljava/math/BigInteger;I)V",
+            &[(&unscaled).into(), JValueGen::Int(value.scale() as i32)],
+        );
+        exception::catch(env, decimal)
+    }
+
+    /// Digits beyond the 28 decimal places of `Decimal` are rounded half to even,
+    /// values whose integer part does not fit in 96 bits fail with [`Error::Conversion`].
+    pub fn to_decimal(unscaled: &[u8], scale: i32) -> Result<Decimal, Error> {
+        let overflow = || {
+            Error::Conversion(
+                format!("BigDecimal with {} byte unscaled value and scale {scale} is out of range for Decimal", unscaled.len()).into(),
+            )
+        };
+        if unscaled.len() > 16 {
+            return Err(overflow());
+        }
+        // Two's complement, big-endian.
+        let negative = unscaled.first().is_some_and(|b| b & 0x80 != 0);
+        let init: i128 = if negative { -1 } else { 0 };
+        let mut mantissa = unscaled.iter().fold(init, |acc, &b| (acc << 8) | b as i128);
+
+        if mantissa == 0 {
+            return Ok(Decimal::ZERO);
+        }
+
+        let mut scale = scale as i64;
+        if scale < 0 {
+            // Any non-zero mantissa times `10^29` is past 96 bits.
+            if -scale > MAX_SCALE {
+                return Err(overflow());
+            }
+            mantissa = mantissa
+                .checked_mul(10i128.pow(-scale as u32))
+                .ok_or_else(overflow)?;
+            scale = 0;
+        }
+
+        let mut excess = (scale - MAX_SCALE).max(0);
+        while excess < scale && mantissa.unsigned_abs() / pow10(excess) > MAX_MANTISSA {
+            excess += 1;
+        }
+        if excess > 0 {
+            mantissa = round_half_even(mantissa, excess);
+            scale -= excess;
+        }
+        if mantissa.unsigned_abs() > MAX_MANTISSA {
+            return Err(overflow());
+        }
+        Ok(Decimal::from_i128_with_scale(mantissa, scale as u32))
+    }
+
+    /// `10^exp`, saturating, `u128` holds up to `10^38`.
+    fn pow10(exp: i64) -> u128 {
+        10u128.checked_pow(exp as u32).unwrap_or(u128::MAX)
+    }
+
+    fn round_half_even(value: i128, digits: i64) -> i128 {
+        let divisor = pow10(digits);
+        let abs = value.unsigned_abs();
+        let (mut quotient, remainder) = (abs / divisor, abs % divisor);
+        if divisor != u128::MAX {
+            let half = divisor / 2;
+            if remainder > half || (remainder == half && quotient % 2 == 1) {
+                quotient += 1;
+            }
+        }
+        let quotient = quotient as i128;
+        if value < 0 {
+            -quotient
+        } else {
+            quotient
+        }
+    }
+}
+
+/// `java.time` <-> chrono and time, field by field to keep nanoseconds.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub mod temporal {
+    use jni::{
+        objects::{AutoLocal, JObject, JValueGen},
+        JNIEnv,
+    };
+
+    use crate::errors::Error;
+
+    use super::exception;
+
+    fn get_int<'a>(env: &mut JNIEnv<'a>, obj: &JObject<'a>, name: &str) -> Result<i32, Error> {
+        let value = env.call_method(obj, name, "()I", &[]);
+        Ok(exception::catch(env, value)?.i()?)
+    }
+
+    /// Year, month and day of a `LocalDate` or `LocalDateTime`.
+    fn date_fields<'a>(env: &mut JNIEnv<'a>, obj: &JObject<'a>) -> Result<[i32; 3], Error> {
+        Ok([
+            get_int(env, obj, "getYear")?,
+            get_int(env, obj, "getMonthValue")?,
+            get_int(env, obj, "getDayOfMonth")?,
+        ])
+    }
+
+    /// Hour, minute, second and nanosecond of a `LocalTime` or `LocalDateTime`.
+    fn time_fields<'a>(env: &mut JNIEnv<'a>, obj: &JObject<'a>) -> Result<[i32; 4], Error> {
+        Ok([
+            get_int(env, obj, "getHour")?,
+            get_int(env, obj, "getMinute")?,
+            get_int(env, obj, "getSecond")?,
+            get_int(env, obj, "getNano")?,
+        ])
+    }
+
+    /// The `LocalDateTime` and the offset in seconds of an `OffsetDateTime`.
+    fn offset_fields<'a>(
+        env: &mut JNIEnv<'a>,
+        obj: &JObject<'a>,
+    ) -> Result<(AutoLocal<'a, JObject<'a>>, i32), Error> {
+        let local = env.call_method(obj, "toLocalDateTime", "()Ljava/time/LocalDateTime;", &[]);
+        let local = AutoLocal::new(exception::catch(env, local)?.l()?, env);
+        let offset = env.call_method(obj, "getOffset", "()Ljava/time/ZoneOffset;", &[]);
+        let offset = AutoLocal::new(exception::catch(env, offset)?.l()?, env);
+        let seconds = get_int(env, &offset, "getTotalSeconds")?;
+        Ok((local, seconds))
+    }
+
+    fn new_local_date<'a>(
+        env: &mut JNIEnv<'a>,
+        [year, month, day]: [i32; 3],
+    ) -> Result<JObject<'a>, Error> {
+        let args = [
+            JValueGen::Int(year),
+            JValueGen::Int(month),
+            JValueGen::Int(day),
+        ];
+        let date = env.call_static_method(
+            "java/time/LocalDate",
+            "of",
+            "(III)Ljava/time/LocalDate;",
+            &args,
+        );
+        Ok(exception::catch(env, date)?.l()?)
+    }
+
+    /// Leap seconds are rejected by `java.time`.
+    fn new_local_time<'a>(
+        env: &mut JNIEnv<'a>,
+        [hour, minute, second, nano]: [i32; 4],
+    ) -> Result<JObject<'a>, Error> {
+        let args = [
+            JValueGen::Int(hour),
+            JValueGen::Int(minute),
+            JValueGen::Int(second),
+            JValueGen::Int(nano),
+        ];
+        let time = env.call_static_method(
+            "java/time/LocalTime",
+            "of",
+            "(IIII)Ljava/time/LocalTime;",
+            &args,
+        );
+        Ok(exception::catch(env, time)?.l()?)
+    }
+
+    fn new_local_date_time<'a>(
+        env: &mut JNIEnv<'a>,
+        date: [i32; 3],
+        time: [i32; 4],
+    ) -> Result<JObject<'a>, Error> {
+        let date = AutoLocal::new(new_local_date(env, date)?, env);
+        let time = AutoLocal::new(new_local_time(env, time)?, env);
+        let date_time = env.call_static_method(
+            "java/time/LocalDateTime",
+            "of",
+            "(Ljava/time/LocalDate;Ljava/time/LocalTime;)Ljava/time/LocalDateTime;",
+            &[(&date).into(), (&time).into()],
+        );
+        Ok(exception::catch(env, date_time)?.l()?)
+    }
+
+    fn new_offset_date_time<'a>(
+        env: &mut JNIEnv<'a>,
+        date: [i32; 3],
+        time: [i32; 4],
+        seconds: i32,
+    ) -> Result<JObject<'a>, Error> {
+        let local = AutoLocal::new(new_local_date_time(env, date, time)?, env);
+        let offset = env.call_static_method(
+            "java/time/ZoneOffset",
+            "ofTotalSeconds",
+            "(I)Ljava/time/ZoneOffset;",
+            &[JValueGen::Int(seconds)],
+        );
+        let offset = AutoLocal::new(exception::catch(env, offset)?.l()?, env);
+        let date_time = env.call_static_method(
+            "java/time/OffsetDateTime",
+            "of",
+            "(Ljava/time/LocalDateTime;Ljava/time/ZoneOffset;)Ljava/time/OffsetDateTime;",
+            &[(&local).into(), (&offset).into()],
+        );
+        Ok(exception::catch(env, date_time)?.l()?)
+    }
+
+    #[cfg(feature = "chrono")]
+    pub mod chrono {
+        use ::chrono::{
+            DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+            Timelike,
+        };
+        use jni::{objects::JObject, JNIEnv};
+
+        use crate::errors::Error;
+
+        use super::*;
+
+        fn out_of_range(value: impl std::fmt::Display) -> Error {
+            Error::Conversion(format!("{value} is out of range").into())
+        }
+
+        /// `java.time.LocalDate`, or the date of a `LocalDateTime`.
+        pub fn from_local_date<'a>(
+            env: &mut JNIEnv<'a>,
+            obj: &JObject<'a>,
+        ) -> Result<NaiveDate, Error> {
+            let [year, month, day] = date_fields(env, obj)?;
+            NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+                .ok_or_else(|| out_of_range(format!("{year}-{month}-{day}")))
+        }
+
+        /// `java.time.LocalTime`, or the time of a `LocalDateTime`.
+        pub fn from_local_time<'a>(
+            env: &mut JNIEnv<'a>,
+            obj: &JObject<'a>,
+        ) -> Result<NaiveTime, Error> {
+            let [hour, minute, second, nano] = time_fields(env, obj)?;
+            NaiveTime::from_hms_nano_opt(hour as u32, minute as u32, second as u32, nano as u32)
+                .ok_or_else(|| out_of_range(format!("{hour}:{minute}:{second}.{nano}")))
+        }
+
+        pub fn from_local_date_time<'a>(
+            env: &mut JNIEnv<'a>,
+            obj: &JObject<'a>,
+        ) -> Result<NaiveDateTime, Error> {
+            Ok(from_local_date(env, obj)?.and_time(from_local_time(env, obj)?))
+        }
+
+        /// `java.time.OffsetDateTime`
+        pub fn from_offset_date_time<'a>(
+            env: &mut JNIEnv<'a>,
+            obj: &JObject<'a>,
+        ) -> Result<DateTime<FixedOffset>, Error> {
+            let (local, seconds) = offset_fields(env, obj)?;
+            let local = from_local_date_time(env, &local)?;
+            let offset = FixedOffset::east_opt(seconds).ok_or_else(|| out_of_range(seconds))?;
+            offset
+                .from_local_datetime(&local)
+                .single()
+                .ok_or_else(|| out_of_range(local))
+        }
+
+        fn date(value: &NaiveDate) -> [i32; 3] {
+            [value.year(), value.month() as i32, value.day() as i32]
+        }
+
+        fn time(value: &NaiveTime) -> [i32; 4] {
+            [
+                value.hour() as i32,
+                value.minute() as i32,
+                value.second() as i32,
+                value.nanosecond() as i32,
+            ]
+        }
+
+        pub fn to_local_date<'a>(
+            env: &mut JNIEnv<'a>,
+            value: &NaiveDate,
+        ) -> Result<JObject<'a>, Error> {
+            new_local_date(env, date(value))
+        }
+
+        pub fn to_local_time<'a>(
+            env: &mut JNIEnv<'a>,
+            value: &NaiveTime,
+        ) -> Result<JObject<'a>, Error> {
+            new_local_time(env, time(value))
+        }
+
+        pub fn to_local_date_time<'a>(
+            env: &mut JNIEnv<'a>,
+            value: &NaiveDateTime,
+        ) -> Result<JObject<'a>, Error> {
+            new_local_date_time(env, date(&value.date()), time(&value.time()))
+        }
+
+        pub fn to_offset_date_time<'a>(
+            env: &mut JNIEnv<'a>,
+            value: &DateTime<FixedOffset>,
+        ) -> Result<JObject<'a>, Error> {
+            let local = value.naive_local();
+            let seconds = value.offset().local_minus_utc();
+            new_offset_date_time(env, date(&local.date()), time(&local.time()), seconds)
+        }
+    }
+
+    #[cfg(feature = "time")]
+    pub mod time {
+        use ::time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+        use jni::{objects::JObject, JNIEnv};
+
+        use crate::errors::Error;
+
+        use super::*;
+
+        fn component_range(err: ::time::error::ComponentRange) -> Error {
+            Error::Conversion(err.into())
+        }
+
+        /// `java.time.LocalDate`, or the date of a `LocalDateTime`.
+        pub fn from_local_date<'a>(env: &mut JNIEnv<'a>, obj: &JObject<'a>) -> Result<Date, Error> {
+            let [year, month, day] = date_fields(env, obj)?;
+            let month = Month::try_from(month as u8).map_err(component_range)?;
+            Date::from_calendar_date(year, month, day as u8).map_err(component_range)
+        }
+
+        /// `java.time.LocalTime`, or the time of a `LocalDateTime`.
+        pub fn from_local_time<'a>(env: &mut JNIEnv<'a>, obj: &JObject<'a>) -> Result<Time, Error> {
+            let [hour, minute, second, nano] = time_fields(env, obj)?;
+            Time::from_hms_nano(hour as u8, minute as u8, second as u8, nano as u32)
+                .map_err(component_range)
+        }
+
+        pub fn from_local_date_time<'a>(
+            env: &mut JNIEnv<'a>,
+            obj: &JObject<'a>,
+        ) -> Result<PrimitiveDateTime, Error> {
+            Ok(PrimitiveDateTime::new(
+                from_local_date(env, obj)?,
+                from_local_time(env, obj)?,
+            ))
+        }
+
+        /// `java.time.OffsetDateTime`
+        pub fn from_offset_date_time<'a>(
+            env: &mut JNIEnv<'a>,
+            obj: &JObject<'a>,
+        ) -> Result<OffsetDateTime, Error> {
+            let (local, seconds) = offset_fields(env, obj)?;
+            let local = from_local_date_time(env, &local)?;
+            let offset = UtcOffset::from_whole_seconds(seconds).map_err(component_range)?;
+            Ok(local.assume_offset(offset))
+        }
+
+        fn date(value: &Date) -> [i32; 3] {
+            [value.year(), value.month() as i32, value.day() as i32]
+        }
+
+        fn time(value: &Time) -> [i32; 4] {
+            [
+                value.hour() as i32,
+                value.minute() as i32,
+                value.second() as i32,
+                value.nanosecond() as i32,
+            ]
+        }
+
+        pub fn to_local_date<'a>(env: &mut JNIEnv<'a>, value: &Date) -> Result<JObject<'a>, Error> {
+            new_local_date(env, date(value))
+        }
+
+        pub fn to_local_time<'a>(env: &mut JNIEnv<'a>, value: &Time) -> Result<JObject<'a>, Error> {
+            new_local_time(env, time(value))
+        }
+
+        pub fn to_local_date_time<'a>(
+            env: &mut JNIEnv<'a>,
+            value: &PrimitiveDateTime,
+        ) -> Result<JObject<'a>, Error> {
+            new_local_date_time(env, date(&value.date()), time(&value.time()))
+        }
+
+        pub fn to_offset_date_time<'a>(
+            env: &mut JNIEnv<'a>,
+            value: &OffsetDateTime,
+        ) -> Result<JObject<'a>, Error> {
+            let seconds = value.offset().whole_seconds();
+            new_offset_date_time(env, date(&value.date()), time(&value.time()), seconds)
+        }
+    }
+}
+
+/// `java.util.UUID` <-> `uuid::Uuid`, through the two 64 bit halves.
+#[cfg(feature = "uuid")]
+pub mod uuid {
+    use ::uuid::Uuid;
+    use jni::{
+        objects::{JByteArray, JObject, JString, JValueGen},
+        JNIEnv,
+    };
+
+    use crate::errors::Error;
+
+    use super::exception;
+
+    /// Reads a `java.util.UUID`, or the 16 byte binary and canonical string forms of
+    /// drivers without a UUID type.
+    pub fn from_java<'a>(env: &mut JNIEnv<'a>, obj: &JObject<'a>) -> Result<Uuid, Error> {
+        if env.is_instance_of(obj, "java/util/UUID")? {
+            let most = env.call_method(obj, "getMostSignificantBits", "()J", &[]);
+            let most = exception::catch(env, most)?.j()?;
+            let least = env.call_method(obj, "getLeastSignificantBits", "()J", &[]);
+            let least = exception::catch(env, least)?.j()?;
+            return Ok(Uuid::from_u64_pair(most as u64, least as u64));
+        }
+        if env.is_instance_of(obj, "[B")? {
+            let bytes = env.convert_byte_array(<&JByteArray>::from(obj))?;
+            return Uuid::from_slice(&bytes).map_err(|err| Error::Conversion(err.into()));
+        }
+        if env.is_instance_of(obj, "java/lang/String")? {
+            let text = String::from(env.get_string(<&JString>::from(obj))?);
+            return Uuid::parse_str(text.trim()).map_err(|err| Error::Conversion(err.into()));
+        }
+        let class = super::get_class_name(env, obj)?;
+        Err(Error::Conversion(format!("{class} is not a UUID").into()))
+    }
+
+    pub fn to_java<'a>(env: &mut JNIEnv<'a>, value: &Uuid) -> Result<JObject<'a>, Error> {
+        let (most, least) = value.as_u64_pair();
+        let args = [JValueGen::Long(most as i64), JValueGen::Long(least as i64)];
+        let uuid = env.new_object("java/util/UUID", "(JJ)V", &args);
+        exception::catch(env, uuid)
+    }
 }
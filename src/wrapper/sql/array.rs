@@ -0,0 +1,315 @@
+use jni::{
+    objects::{
+        AutoLocal, JBooleanArray, JByteArray, JDoubleArray, JFloatArray, JIntArray, JLongArray,
+        JObject, JObjectArray, JShortArray, JString, JValue, JValueGen,
+    },
+    JNIEnv,
+};
+
+use crate::{errors::Error, util};
+
+/// An element of a `java.sql.Array`, read by
+/// [`ResultSet::get_array`](super::ResultSet::get_array) and bound by
+/// [`PreparedStatement::set_array`](super::PreparedStatement::set_array).
+pub trait ArrayElement: Sized {
+    /// Converts a boxed element of an `Object[]`, or an element of a primitive array.
+    fn from_java<'local>(
+        env: &mut JNIEnv<'local>,
+        value: JValue<'local, '_>,
+    ) -> Result<Self, Error>;
+
+    /// Boxes the element for `Connection.createArrayOf`.
+    fn to_java<'local>(&self, env: &mut JNIEnv<'local>) -> Result<JObject<'local>, Error>;
+}
+
+/// The values of [`PreparedStatement::set_array`](super::PreparedStatement::set_array),
+/// `None` binds `NULL`.
+///
+/// Implemented for `&[T]`, `&[T; N]`, `&Vec<T>` and `Option<&[T]>`.
+pub trait ArrayParam<'a, T> {
+    fn into_slice(self) -> Option<&'a [T]>;
+}
+
+impl<'a, T: 'a, A: AsRef<[T]> + ?Sized> ArrayParam<'a, T> for &'a A {
+    fn into_slice(self) -> Option<&'a [T]> {
+        Some(self.as_ref())
+    }
+}
+
+impl<'a, T> ArrayParam<'a, T> for Option<&'a [T]> {
+    fn into_slice(self) -> Option<&'a [T]> {
+        self
+    }
+}
+
+fn mismatch<'local>(env: &mut JNIEnv<'local>, value: JValue<'local, '_>, expected: &str) -> Error {
+    let name = match value {
+        JValueGen::Object(obj) => match util::get_class_name(env, obj) {
+            Ok(class) => class,
+            Err(err) => return err.into(),
+        },
+        value => value.type_name().to_string(),
+    };
+    Error::Conversion(format!("{name} is not a {expected}").into())
+}
+
+/// `Number.longValue()`/`doubleValue()`/`floatValue()` of a boxed element.
+fn number_value<'local>(
+    env: &mut JNIEnv<'local>,
+    obj: &JObject<'local>,
+    name: &str,
+    sig: &str,
+) -> Result<JValueGen<JObject<'local>>, Error> {
+    if !env.is_instance_of(obj, "java/lang/Number")? {
+        let class = util::get_class_name(env, obj)?;
+        return Err(Error::Conversion(format!("{class} is not a number").into()));
+    }
+    let value = env.call_method(obj, name, sig, &[]);
+    util::exception::catch(env, value)
+}
+
+/// `Integer.valueOf(value)` and the like.
+fn box_value<'local>(
+    env: &mut JNIEnv<'local>,
+    class: &str,
+    sig: &str,
+    value: JValue<'_, '_>,
+) -> Result<JObject<'local>, Error> {
+    let value = env.call_static_method(class, "valueOf", sig, &[value]);
+    Ok(util::exception::catch(env, value)?.l()?)
+}
+
+macro_rules! integer {
+    ($($type:ty => $class:literal, $sig:literal, $variant:ident;)*) => {
+        $(
+            impl ArrayElement for $type {
+                fn from_java<'local>(
+                    env: &mut JNIEnv<'local>,
+                    value: JValue<'local, '_>,
+                ) -> Result<Self, Error> {
+                    let long = match value {
+                        JValueGen::Byte(value) => value as i64,
+                        JValueGen::Short(value) => value as i64,
+                        JValueGen::Int(value) => value as i64,
+                        JValueGen::Long(value) => value,
+                        JValueGen::Object(obj) => number_value(env, obj, "longValue", "()J")?.j()?,
+                        _ => return Err(mismatch(env, value, stringify!($type))),
+                    };
+                    <$type>::try_from(long).map_err(|err| Error::Conversion(err.into()))
+                }
+
+                fn to_java<'local>(&self, env: &mut JNIEnv<'local>) -> Result<JObject<'local>, Error> {
+                    box_value(env, $class, $sig, JValueGen::$variant(*self))
+                }
+            }
+        )*
+    };
+}
+
+integer! {
+    i16 => "java/lang/Short", "(S)Ljava/lang/Short;", Short;
+    i32 => "java/lang/Integer", "(I)Ljava/lang/Integer;", Int;
+    i64 => "java/lang/Long", "(J)Ljava/lang/Long;", Long;
+}
+
+impl ArrayElement for f32 {
+    fn from_java<'local>(
+        env: &mut JNIEnv<'local>,
+        value: JValue<'local, '_>,
+    ) -> Result<Self, Error> {
+        match value {
+            JValueGen::Float(value) => Ok(value),
+            JValueGen::Object(obj) => Ok(number_value(env, obj, "floatValue", "()F")?.f()?),
+            _ => Err(mismatch(env, value, "f32")),
+        }
+    }
+
+    fn to_java<'local>(&self, env: &mut JNIEnv<'local>) -> Result<JObject<'local>, Error> {
+        box_value(
+            env,
+            "java/lang/Float",
+            "(F)Ljava/lang/Float;",
+            JValueGen::Float(*self),
+        )
+    }
+}
+
+impl ArrayElement for f64 {
+    fn from_java<'local>(
+        env: &mut JNIEnv<'local>,
+        value: JValue<'local, '_>,
+    ) -> Result<Self, Error> {
+        match value {
+            JValueGen::Float(value) => Ok(value as f64),
+            JValueGen::Double(value) => Ok(value),
+            JValueGen::Object(obj) => Ok(number_value(env, obj, "doubleValue", "()D")?.d()?),
+            _ => Err(mismatch(env, value, "f64")),
+        }
+    }
+
+    fn to_java<'local>(&self, env: &mut JNIEnv<'local>) -> Result<JObject<'local>, Error> {
+        box_value(
+            env,
+            "java/lang/Double",
+            "(D)Ljava/lang/Double;",
+            JValueGen::Double(*self),
+        )
+    }
+}
+
+impl ArrayElement for bool {
+    fn from_java<'local>(
+        env: &mut JNIEnv<'local>,
+        value: JValue<'local, '_>,
+    ) -> Result<Self, Error> {
+        match value {
+            JValueGen::Bool(value) => Ok(value != 0),
+            JValueGen::Object(obj) if env.is_instance_of(obj, "java/lang/Boolean")? => {
+                let value = env.call_method(obj, "booleanValue", "()Z", &[]);
+                Ok(util::exception::catch(env, value)?.z()?)
+            }
+            _ => Err(mismatch(env, value, "bool")),
+        }
+    }
+
+    fn to_java<'local>(&self, env: &mut JNIEnv<'local>) -> Result<JObject<'local>, Error> {
+        let value = JValueGen::Bool(u8::from(*self));
+        box_value(env, "java/lang/Boolean", "(Z)Ljava/lang/Boolean;", value)
+    }
+}
+
+impl ArrayElement for String {
+    fn from_java<'local>(
+        env: &mut JNIEnv<'local>,
+        value: JValue<'local, '_>,
+    ) -> Result<Self, Error> {
+        match value {
+            JValueGen::Object(obj) if env.is_instance_of(obj, "java/lang/String")? => {
+                Ok(env.get_string(<&JString>::from(obj))?.into())
+            }
+            _ => Err(mismatch(env, value, "String")),
+        }
+    }
+
+    fn to_java<'local>(&self, env: &mut JNIEnv<'local>) -> Result<JObject<'local>, Error> {
+        Ok(env.new_string(self)?.into())
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl ArrayElement for rust_decimal::Decimal {
+    fn from_java<'local>(
+        env: &mut JNIEnv<'local>,
+        value: JValue<'local, '_>,
+    ) -> Result<Self, Error> {
+        match value {
+            JValueGen::Object(obj) if env.is_instance_of(obj, "java/math/BigDecimal")? => {
+                util::decimal::from_java(env, obj)
+            }
+            _ => Err(mismatch(env, value, "Decimal")),
+        }
+    }
+
+    fn to_java<'local>(&self, env: &mut JNIEnv<'local>) -> Result<JObject<'local>, Error> {
+        util::decimal::to_java(env, self)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ArrayElement for uuid::Uuid {
+    fn from_java<'local>(
+        env: &mut JNIEnv<'local>,
+        value: JValue<'local, '_>,
+    ) -> Result<Self, Error> {
+        match value {
+            JValueGen::Object(obj) => util::uuid::from_java(env, obj),
+            _ => Err(mismatch(env, value, "Uuid")),
+        }
+    }
+
+    fn to_java<'local>(&self, env: &mut JNIEnv<'local>) -> Result<JObject<'local>, Error> {
+        util::uuid::to_java(env, self)
+    }
+}
+
+/// Reads `Array.getArray()` and frees the array, `NULL` elements are `None`.
+pub(crate) fn from_java_array<'local, T: ArrayElement>(
+    env: &mut JNIEnv<'local>,
+    array: &JObject<'local>,
+) -> Result<Vec<Option<T>>, Error> {
+    let elements = env.call_method(array, "getArray", "()Ljava/lang/Object;", &[]);
+    let elements = AutoLocal::new(util::exception::catch(env, elements)?.l()?, env);
+    let values = unpack(env, &elements);
+    util::lob::free(env, array);
+    values
+}
+
+macro_rules! primitive_arrays {
+    ($env:ident, $elements:ident, $($signature:literal => $array:ty, $region:ident, $variant:ident;)*) => {
+        $(
+            if $env.is_instance_of($elements, $signature)? {
+                let elements = <&$array>::from($elements);
+                let mut buffer = vec![Default::default(); $env.get_array_length(elements)? as usize];
+                $env.$region(elements, 0, &mut buffer)?;
+                return buffer
+                    .into_iter()
+                    .map(|value| T::from_java($env, JValueGen::$variant(value)).map(Some))
+                    .collect();
+            }
+        )*
+    };
+}
+
+fn unpack<'local, T: ArrayElement>(
+    env: &mut JNIEnv<'local>,
+    elements: &JObject<'local>,
+) -> Result<Vec<Option<T>>, Error> {
+    if env.is_instance_of(elements, "[Ljava/lang/Object;")? {
+        let elements = <&JObjectArray>::from(elements);
+        let length = env.get_array_length(elements)?;
+        let mut values = Vec::with_capacity(length as usize);
+        for i in 0..length {
+            let element = AutoLocal::new(env.get_object_array_element(elements, i)?, env);
+            if element.is_null() {
+                values.push(None);
+            } else {
+                values.push(Some(T::from_java(env, JValueGen::Object(&element))?));
+            }
+        }
+        return Ok(values);
+    }
+    primitive_arrays! {
+        env, elements,
+        "[Z" => JBooleanArray, get_boolean_array_region, Bool;
+        "[B" => JByteArray, get_byte_array_region, Byte;
+        "[S" => JShortArray, get_short_array_region, Short;
+        "[I" => JIntArray, get_int_array_region, Int;
+        "[J" => JLongArray, get_long_array_region, Long;
+        "[F" => JFloatArray, get_float_array_region, Float;
+        "[D" => JDoubleArray, get_double_array_region, Double;
+    }
+    let class = util::get_class_name(env, elements)?;
+    Err(Error::Conversion(format!("{class} is not an array").into()))
+}
+
+/// Boxes the values into an `Object[]` for `Connection.createArrayOf`.
+pub(crate) fn to_java_array<'local, T: ArrayElement>(
+    env: &mut JNIEnv<'local>,
+    values: &[T],
+) -> Result<JObjectArray<'local>, Error> {
+    let array = env.new_object_array(values.len() as i32, "java/lang/Object", JObject::null())?;
+    for (i, value) in values.iter().enumerate() {
+        let element = match value.to_java(env) {
+            Ok(element) => AutoLocal::new(element, env),
+            Err(err) => {
+                env.delete_local_ref(array)?;
+                return Err(err);
+            }
+        };
+        if let Err(err) = env.set_object_array_element(&array, i as i32, &element) {
+            env.delete_local_ref(array)?;
+            return Err(err.into());
+        }
+    }
+    Ok(array)
+}
@@ -0,0 +1,601 @@
+use std::{
+    io::Read,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use jni::{
+    objects::{AutoLocal, JMethodID, JObject, JValueGen},
+    signature::{Primitive, ReturnType},
+    sys::jvalue,
+    JNIEnv,
+};
+
+use crate::{errors::Error, util, Connection};
+
+use super::{
+    ArrayElement, ArrayParam, FetchDirection, PreparedStatement, ResultSet, SqlType,
+    StatementOptions, StrParam, ToSql,
+};
+
+/// `java.sql.CallableStatement`, created by [`Connection::prepare_call`].
+///
+/// IN parameters are set like on a [`PreparedStatement`], OUT parameters must be
+/// registered with [`CallableStatement::register_out_parameter`] before execution.
+pub struct CallableStatement<'local> {
+    statement: PreparedStatement<'local>,
+    register_out_parameter: (JMethodID, JMethodID),
+    was_null: JMethodID,
+    get_object: (JMethodID, JMethodID),
+    get_string: (JMethodID, JMethodID),
+    get_short: (JMethodID, JMethodID),
+    get_int: (JMethodID, JMethodID),
+    get_long: (JMethodID, JMethodID),
+    get_float: (JMethodID, JMethodID),
+    get_double: (JMethodID, JMethodID),
+    get_boolean: (JMethodID, JMethodID),
+    set_string: JMethodID,
+    set_short: JMethodID,
+    set_int: JMethodID,
+    set_long: JMethodID,
+    set_float: JMethodID,
+    set_double: JMethodID,
+    set_bool: JMethodID,
+    set_null: JMethodID,
+    #[cfg(feature = "rust_decimal")]
+    get_big_decimal: (JMethodID, JMethodID),
+    #[cfg(feature = "rust_decimal")]
+    set_big_decimal: JMethodID,
+    env: JNIEnv<'local>,
+    conn: &'local Connection<'local>,
+}
+
+impl<'local> CallableStatement<'local> {
+    pub fn from_ref(
+        conn: &'local Connection<'local>,
+        statement: JObject<'local>,
+    ) -> Result<Self, Error> {
+        let statement = PreparedStatement::from_ref(conn, statement)?;
+        let mut env = unsafe { conn.env() };
+
+        let class = AutoLocal::new(env.find_class("java/sql/CallableStatement")?, &env);
+
+        let register_out_parameter = env.get_method_id(&class, "registerOutParameter", "(II)V")?;
+        let register_out_parameter_by_name =
+            env.get_method_id(&class, "registerOutParameter", "(Ljava/lang/String;I)V")?;
+
+        let was_null = env.get_method_id(&class, "wasNull", "()Z")?;
+
+        let get_object = env.get_method_id(&class, "getObject", "(I)Ljava/lang/Object;")?;
+        let get_object_by_name = env.get_method_id(
+            &class,
+            "getObject",
+            "(Ljava/lang/String;)Ljava/lang/Object;",
+        )?;
+
+        let get_string = env.get_method_id(&class, "getString", "(I)Ljava/lang/String;")?;
+        let get_string_by_name = env.get_method_id(
+            &class,
+            "getString",
+            "(Ljava/lang/String;)Ljava/lang/String;",
+        )?;
+
+        let get_short = env.get_method_id(&class, "getShort", "(I)S")?;
+        let get_short_by_name = env.get_method_id(&class, "getShort", "(Ljava/lang/String;)S")?;
+
+        let get_int = env.get_method_id(&class, "getInt", "(I)I")?;
+        let get_int_by_name = env.get_method_id(&class, "getInt", "(Ljava/lang/String;)I")?;
+
+        let get_long = env.get_method_id(&class, "getLong", "(I)J")?;
+        let get_long_by_name = env.get_method_id(&class, "getLong", "(Ljava/lang/String;)J")?;
+
+        let get_float = env.get_method_id(&class, "getFloat", "(I)F")?;
+        let get_float_by_name = env.get_method_id(&class, "getFloat", "(Ljava/lang/String;)F")?;
+
+        let get_double = env.get_method_id(&class, "getDouble", "(I)D")?;
+        let get_double_by_name = env.get_method_id(&class, "getDouble", "(Ljava/lang/String;)D")?;
+
+        let get_boolean = env.get_method_id(&class, "getBoolean", "(I)Z")?;
+        let get_boolean_by_name =
+            env.get_method_id(&class, "getBoolean", "(Ljava/lang/String;)Z")?;
+
+        let set_string = env.get_method_id(
+            &class,
+            "setString",
+            "(Ljava/lang/String;Ljava/lang/String;)V",
+        )?;
+        let set_short = env.get_method_id(&class, "setShort", "(Ljava/lang/String;S)V")?;
+        let set_int = env.get_method_id(&class, "setInt", "(Ljava/lang/String;I)V")?;
+        let set_long = env.get_method_id(&class, "setLong", "(Ljava/lang/String;J)V")?;
+        let set_float = env.get_method_id(&class, "setFloat", "(Ljava/lang/String;F)V")?;
+        let set_double = env.get_method_id(&class, "setDouble", "(Ljava/lang/String;D)V")?;
+        let set_bool = env.get_method_id(&class, "setBoolean", "(Ljava/lang/String;Z)V")?;
+        let set_null = env.get_method_id(&class, "setNull", "(Ljava/lang/String;I)V")?;
+
+        #[cfg(feature = "rust_decimal")]
+        let get_big_decimal = (
+            env.get_method_id(&class, "getBigDecimal", "(I)Ljava/math/BigDecimal;")?,
+            env.get_method_id(
+                &class,
+                "getBigDecimal",
+                "(Ljava/lang/String;)Ljava/math/BigDecimal;",
+            )?,
+        );
+        #[cfg(feature = "rust_decimal")]
+        let set_big_decimal = env.get_method_id(
+            &class,
+            "setBigDecimal",
+            "(Ljava/lang/String;Ljava/math/BigDecimal;)V",
+        )?;
+
+        Ok(CallableStatement {
+            statement,
+            register_out_parameter: (register_out_parameter, register_out_parameter_by_name),
+            was_null,
+            get_object: (get_object, get_object_by_name),
+            get_string: (get_string, get_string_by_name),
+            get_short: (get_short, get_short_by_name),
+            get_int: (get_int, get_int_by_name),
+            get_long: (get_long, get_long_by_name),
+            get_float: (get_float, get_float_by_name),
+            get_double: (get_double, get_double_by_name),
+            get_boolean: (get_boolean, get_boolean_by_name),
+            set_string,
+            set_short,
+            set_int,
+            set_long,
+            set_float,
+            set_double,
+            set_bool,
+            set_null,
+            #[cfg(feature = "rust_decimal")]
+            get_big_decimal,
+            #[cfg(feature = "rust_decimal")]
+            set_big_decimal,
+            env,
+            conn,
+        })
+    }
+
+    delegate! {
+        set_null(index: i32, sql_type: SqlType);
+        set_string<'a>(index: i32, value: impl StrParam<'a>);
+        set_char(index: i32, value: impl Into<Option<char>>);
+        set_byte(index: i32, value: impl Into<Option<i8>>);
+        set_short(index: i32, value: impl Into<Option<i16>>);
+        set_int(index: i32, value: impl Into<Option<i32>>);
+        set_long(index: i32, value: impl Into<Option<i64>>);
+        set_float(index: i32, value: impl Into<Option<f32>>);
+        set_double(index: i32, value: impl Into<Option<f64>>);
+        set_boolean(index: i32, value: impl Into<Option<bool>>);
+        set_bytes<'a>(index: i32, value: impl Into<Option<&'a [u8]>>);
+        set_blob<'a>(index: i32, value: impl Into<Option<&'a [u8]>>);
+        set_clob<'a>(index: i32, value: impl StrParam<'a>);
+        set_nstring<'a>(index: i32, value: impl StrParam<'a>);
+        set_binary_stream(index: i32, reader: impl Read + Send + 'local, length: i64);
+        set_ascii_stream(index: i32, reader: impl Read + Send + 'local, length: i64);
+        set_character_stream(index: i32, reader: impl Read + Send + 'local);
+        #[cfg(feature = "rust_decimal")]
+        set_decimal(index: i32, value: impl Into<Option<rust_decimal::Decimal>>);
+        #[cfg(feature = "time")]
+        set_time_date(index: i32, value: impl Into<Option<time::Date>>);
+        #[cfg(feature = "time")]
+        set_time_time(index: i32, value: impl Into<Option<time::Time>>);
+        #[cfg(feature = "time")]
+        set_time_primitive_date_time(index: i32, value: impl Into<Option<time::PrimitiveDateTime>>);
+        #[cfg(feature = "time")]
+        set_time_offset_date_time(index: i32, value: impl Into<Option<time::OffsetDateTime>>);
+        #[cfg(feature = "uuid")]
+        set_uuid(index: i32, value: impl Into<Option<uuid::Uuid>>);
+        #[cfg(feature = "chrono")]
+        set_naive_date(index: i32, value: impl Into<Option<chrono::NaiveDate>>);
+        #[cfg(feature = "chrono")]
+        set_naive_time(index: i32, value: impl Into<Option<chrono::NaiveTime>>);
+        #[cfg(feature = "chrono")]
+        set_naive_date_time(index: i32, value: impl Into<Option<chrono::NaiveDateTime>>);
+        #[cfg(feature = "chrono")]
+        set_offset_date_time(index: i32, value: impl Into<Option<chrono::DateTime<chrono::FixedOffset>>>);
+        add_batch();
+        clear_batch();
+        set_query_timeout(timeout: Duration);
+        set_fetch_size(rows: i32);
+        set_max_rows(max: i32);
+        set_large_max_rows(max: i64);
+        set_fetch_direction(direction: FetchDirection);
+    }
+
+    pub fn bind<T: ToSql + ?Sized>(mut self, index: i32, value: &T) -> Result<Self, Error> {
+        self.statement = self.statement.bind(index, value)?;
+        Ok(self)
+    }
+
+    pub fn set_array<'a, T: ArrayElement + 'a>(
+        mut self,
+        index: i32,
+        type_name: &str,
+        values: impl ArrayParam<'a, T>,
+    ) -> Result<Self, Error> {
+        self.statement = self.statement.set_array(index, type_name, values)?;
+        Ok(self)
+    }
+
+    #[cfg(feature = "serde_json")]
+    pub fn set_json<'a, T: serde::Serialize + ?Sized + 'a>(
+        mut self,
+        index: i32,
+        value: impl Into<Option<&'a T>>,
+    ) -> Result<Self, Error> {
+        self.statement = self.statement.set_json(index, value)?;
+        Ok(self)
+    }
+
+    pub(crate) fn apply_options(mut self, options: &StatementOptions) -> Result<Self, Error> {
+        self.statement = self.statement.apply_options(options)?;
+        Ok(self)
+    }
+
+    pub fn register_out_parameter(mut self, index: i32, sql_type: SqlType) -> Result<Self, Error> {
+        let method = &self.register_out_parameter.0;
+        let args = [jvalue { i: index }, jvalue { i: sql_type.into() }];
+        util::call::void(&mut self.env, self.statement.as_obj(), method, &args)?;
+        Ok(self)
+    }
+
+    pub fn register_out_parameter_by_name(
+        mut self,
+        name: &str,
+        sql_type: SqlType,
+    ) -> Result<Self, Error> {
+        let method = self.register_out_parameter.1;
+        self.set_param_by_name(method, name, jvalue { i: sql_type.into() })?;
+        Ok(self)
+    }
+
+    pub fn set_null_by_name(mut self, name: &str, sql_type: SqlType) -> Result<Self, Error> {
+        self.set_param_by_name(self.set_null, name, jvalue { i: sql_type.into() })?;
+        Ok(self)
+    }
+
+    pub fn set_string_by_name<'a>(
+        mut self,
+        name: &str,
+        value: impl StrParam<'a>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into_str() else {
+            return self.set_null_by_name(name, SqlType::VarChar);
+        };
+        let value: JObject<'local> = self.env.new_string(value)?.into();
+        let result =
+            self.set_param_by_name(self.set_string, name, JValueGen::Object(&value).as_jni());
+        self.env.delete_local_ref(value)?;
+        result?;
+        Ok(self)
+    }
+    pub fn set_short_by_name(
+        mut self,
+        name: &str,
+        value: impl Into<Option<i16>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null_by_name(name, SqlType::SmallInt);
+        };
+        self.set_param_by_name(self.set_short, name, jvalue { s: value })?;
+        Ok(self)
+    }
+    pub fn set_int_by_name(
+        mut self,
+        name: &str,
+        value: impl Into<Option<i32>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null_by_name(name, SqlType::Integer);
+        };
+        self.set_param_by_name(self.set_int, name, jvalue { i: value })?;
+        Ok(self)
+    }
+    pub fn set_long_by_name(
+        mut self,
+        name: &str,
+        value: impl Into<Option<i64>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null_by_name(name, SqlType::BigInt);
+        };
+        self.set_param_by_name(self.set_long, name, jvalue { j: value })?;
+        Ok(self)
+    }
+    pub fn set_float_by_name(
+        mut self,
+        name: &str,
+        value: impl Into<Option<f32>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null_by_name(name, SqlType::Real);
+        };
+        self.set_param_by_name(self.set_float, name, jvalue { f: value })?;
+        Ok(self)
+    }
+    pub fn set_double_by_name(
+        mut self,
+        name: &str,
+        value: impl Into<Option<f64>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null_by_name(name, SqlType::Double);
+        };
+        self.set_param_by_name(self.set_double, name, jvalue { d: value })?;
+        Ok(self)
+    }
+    pub fn set_boolean_by_name(
+        mut self,
+        name: &str,
+        value: impl Into<Option<bool>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null_by_name(name, SqlType::Boolean);
+        };
+        self.set_param_by_name(self.set_bool, name, util::cast::bool_to_jvalue(value))?;
+        Ok(self)
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    pub fn set_decimal_by_name(
+        mut self,
+        name: &str,
+        value: impl Into<Option<rust_decimal::Decimal>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null_by_name(name, SqlType::Decimal);
+        };
+        let value = util::decimal::to_java(&mut self.env, &value)?;
+        let result = self.set_param_by_name(
+            self.set_big_decimal,
+            name,
+            JValueGen::Object(&value).as_jni(),
+        );
+        self.env.delete_local_ref(value)?;
+        result?;
+        Ok(self)
+    }
+
+    pub fn was_null(&self) -> Result<bool, Error> {
+        let mut env = unsafe { self.conn.env() };
+        util::call::get_bool(&mut env, self.statement.as_obj(), &self.was_null)
+    }
+
+    pub fn get_string(&self, index: i32) -> Result<Option<String>, Error> {
+        let method = &self.get_string.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            util::cast::value_cast_string(env, value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_string_by_name(&self, name: &str) -> Result<Option<String>, Error> {
+        let method = &self.get_string.1;
+        self.use_name(method, name, ReturnType::Object, |env, value| {
+            util::cast::value_cast_string(env, value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_short(&self, index: i32) -> Result<Option<i16>, Error> {
+        let method = &self.get_short.0;
+        let r_type = ReturnType::Primitive(Primitive::Short);
+        self.use_index(method, index, r_type, |_, value| {
+            util::cast::value_cast_i16(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_short_by_name(&self, name: &str) -> Result<Option<i16>, Error> {
+        let method = &self.get_short.1;
+        let r_type = ReturnType::Primitive(Primitive::Short);
+        self.use_name(method, name, r_type, |_, value| {
+            util::cast::value_cast_i16(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_int(&self, index: i32) -> Result<Option<i32>, Error> {
+        let method = &self.get_int.0;
+        let r_type = ReturnType::Primitive(Primitive::Int);
+        self.use_index(method, index, r_type, |_, value| {
+            util::cast::value_cast_i32(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_int_by_name(&self, name: &str) -> Result<Option<i32>, Error> {
+        let method = &self.get_int.1;
+        let r_type = ReturnType::Primitive(Primitive::Int);
+        self.use_name(method, name, r_type, |_, value| {
+            util::cast::value_cast_i32(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_long(&self, index: i32) -> Result<Option<i64>, Error> {
+        let method = &self.get_long.0;
+        let r_type = ReturnType::Primitive(Primitive::Long);
+        self.use_index(method, index, r_type, |_, value| {
+            util::cast::value_cast_i64(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_long_by_name(&self, name: &str) -> Result<Option<i64>, Error> {
+        let method = &self.get_long.1;
+        let r_type = ReturnType::Primitive(Primitive::Long);
+        self.use_name(method, name, r_type, |_, value| {
+            util::cast::value_cast_i64(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_float(&self, index: i32) -> Result<Option<f32>, Error> {
+        let method = &self.get_float.0;
+        let r_type = ReturnType::Primitive(Primitive::Float);
+        self.use_index(method, index, r_type, |_, value| {
+            util::cast::value_cast_f32(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_float_by_name(&self, name: &str) -> Result<Option<f32>, Error> {
+        let method = &self.get_float.1;
+        let r_type = ReturnType::Primitive(Primitive::Float);
+        self.use_name(method, name, r_type, |_, value| {
+            util::cast::value_cast_f32(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_double(&self, index: i32) -> Result<Option<f64>, Error> {
+        let method = &self.get_double.0;
+        let r_type = ReturnType::Primitive(Primitive::Double);
+        self.use_index(method, index, r_type, |_, value| {
+            util::cast::value_cast_f64(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_double_by_name(&self, name: &str) -> Result<Option<f64>, Error> {
+        let method = &self.get_double.1;
+        let r_type = ReturnType::Primitive(Primitive::Double);
+        self.use_name(method, name, r_type, |_, value| {
+            util::cast::value_cast_f64(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_boolean(&self, index: i32) -> Result<Option<bool>, Error> {
+        let method = &self.get_boolean.0;
+        let r_type = ReturnType::Primitive(Primitive::Boolean);
+        self.use_index(method, index, r_type, |_, value| {
+            util::cast::value_cast_bool(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_boolean_by_name(&self, name: &str) -> Result<Option<bool>, Error> {
+        let method = &self.get_boolean.1;
+        let r_type = ReturnType::Primitive(Primitive::Boolean);
+        self.use_name(method, name, r_type, |_, value| {
+            util::cast::value_cast_bool(value).map_err(Error::from)
+        })
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    pub fn get_decimal(&self, index: i32) -> Result<Option<rust_decimal::Decimal>, Error> {
+        let method = &self.get_big_decimal.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            let value = AutoLocal::new(value.l()?, env);
+            util::decimal::from_java(env, &value)
+        })
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    pub fn get_decimal_by_name(&self, name: &str) -> Result<Option<rust_decimal::Decimal>, Error> {
+        let method = &self.get_big_decimal.1;
+        self.use_name(method, name, ReturnType::Object, |env, value| {
+            let value = AutoLocal::new(value.l()?, env);
+            util::decimal::from_java(env, &value)
+        })
+    }
+
+    /// A cursor OUT parameter, registered as [`SqlType::RefCursor`] or a vendor cursor type.
+    pub fn get_cursor(&self, index: i32) -> Result<Option<ResultSet<'local>>, Error> {
+        let method = &self.get_object.0;
+        self.use_index(method, index, ReturnType::Object, |_, value| {
+            self.cast_result_set(value)
+        })
+    }
+
+    pub fn get_cursor_by_name(&self, name: &str) -> Result<Option<ResultSet<'local>>, Error> {
+        let method = &self.get_object.1;
+        self.use_name(method, name, ReturnType::Object, |_, value| {
+            self.cast_result_set(value)
+        })
+    }
+
+    fn cast_result_set(
+        &self,
+        value: JValueGen<JObject<'local>>,
+    ) -> Result<ResultSet<'local>, Error> {
+        if let JValueGen::Object(obj) = value {
+            return ResultSet::from_ref(self.conn, obj);
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    fn set_param_by_name(
+        &mut self,
+        method: JMethodID,
+        name: &str,
+        value: jvalue,
+    ) -> Result<(), Error> {
+        let name: JObject<'local> = self.env.new_string(name)?.into();
+        let args = [JValueGen::Object(&name).as_jni(), value];
+        let result = util::call::void(&mut self.env, self.statement.as_obj(), &method, &args);
+        self.env.delete_local_ref(name)?;
+        result
+    }
+
+    fn use_index<T, F>(
+        &self,
+        method: &JMethodID,
+        index: i32,
+        r_type: ReturnType,
+        f: F,
+    ) -> Result<Option<T>, Error>
+    where
+        F: Fn(&mut JNIEnv<'local>, JValueGen<JObject<'local>>) -> Result<T, Error>,
+    {
+        let mut env: JNIEnv<'local> = unsafe { self.conn.env() };
+        let value = unsafe {
+            env.call_method_unchecked(
+                self.statement.as_obj(),
+                method,
+                r_type,
+                &[jvalue { i: index }],
+            )
+        };
+        let value = util::exception::catch(&mut env, value)?;
+        if util::call::get_bool(&mut env, self.statement.as_obj(), &self.was_null)? {
+            util::delete_value(&mut env, value)?;
+            return Ok(None);
+        }
+        f(&mut env, value).map(Some)
+    }
+
+    fn use_name<T, F>(
+        &self,
+        method: &JMethodID,
+        name: &str,
+        r_type: ReturnType,
+        f: F,
+    ) -> Result<Option<T>, Error>
+    where
+        F: Fn(&mut JNIEnv<'local>, JValueGen<JObject<'local>>) -> Result<T, Error>,
+    {
+        let mut env: JNIEnv<'local> = unsafe { self.conn.env() };
+        let name: JObject<'_> = env.new_string(name)?.into();
+        let value = unsafe {
+            env.call_method_unchecked(
+                self.statement.as_obj(),
+                method,
+                r_type,
+                &[JValueGen::Object(&name).as_jni()],
+            )
+        };
+        env.delete_local_ref(name)?;
+        let value = util::exception::catch(&mut env, value)?;
+        if util::call::get_bool(&mut env, self.statement.as_obj(), &self.was_null)? {
+            util::delete_value(&mut env, value)?;
+            return Ok(None);
+        }
+        f(&mut env, value).map(Some)
+    }
+}
+
+impl<'local> Deref for CallableStatement<'local> {
+    type Target = PreparedStatement<'local>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.statement
+    }
+}
+
+impl<'local> DerefMut for CallableStatement<'local> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.statement
+    }
+}
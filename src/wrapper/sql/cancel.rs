@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use jni::{
+    objects::{AutoLocal, GlobalRef, JMethodID, JObject},
+    JNIEnv, JavaVM,
+};
+
+use crate::{errors::Error, util};
+
+/// Cancels a running statement from another thread, e.g. a timeout watchdog.
+///
+/// Holds its own global reference to the statement and attaches the calling thread
+/// to the JVM, so it is `Send` and may outlive the thread that created it.
+#[derive(Debug, Clone)]
+pub struct CancelHandle {
+    vm: Arc<JavaVM>,
+    statement: GlobalRef,
+    cancel: JMethodID,
+}
+
+impl CancelHandle {
+    pub fn new<'local>(
+        env: &mut JNIEnv<'local>,
+        statement: &JObject<'local>,
+    ) -> Result<Self, Error> {
+        let vm = env.get_java_vm()?;
+        let class = AutoLocal::new(env.find_class("java/sql/Statement")?, env);
+        let cancel = env.get_method_id(&class, "cancel", "()V")?;
+        let statement = env.new_global_ref(statement)?;
+        Ok(CancelHandle {
+            vm: Arc::new(vm),
+            statement,
+            cancel,
+        })
+    }
+
+    /// `Statement.cancel()`, the cancelled execution fails with an `Error::Sql`.
+    ///
+    /// Does nothing if the statement is not running, fails if it is already closed.
+    pub fn cancel(&self) -> Result<(), Error> {
+        let mut env = self.vm.attach_current_thread()?;
+        util::call::void(&mut env, self.statement.as_obj(), &self.cancel, &[])
+    }
+}
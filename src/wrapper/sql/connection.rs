@@ -1,19 +1,50 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    cell::{Cell, OnceCell},
+    ops::{Deref, DerefMut},
+};
 
 use jni::{
-    objects::{AutoLocal, JMethodID, JObject, JValueGen},
+    objects::{AutoLocal, JMethodID, JObject, JObjectArray, JValueGen},
     signature::ReturnType,
+    sys::jvalue,
     AttachGuard, JNIEnv,
 };
 
 use crate::{errors::Error, util};
 
-use super::PreparedStatement;
+use super::{
+    Blob, CallableStatement, Clob, FromRow, FromSql, IsolationLevel, NamedSql, NamedStatement,
+    Params, PreparedStatement, Rows, Savepoint, Statement, StatementOptions, Transaction,
+    TransactionBuilder,
+};
 
 pub struct Connection<'local> {
     inner: AutoLocal<'local, JObject<'local>>,
     guard: AttachGuard<'local>,
     prepare_statement: JMethodID,
+    prepare_statement_auto_keys: JMethodID,
+    prepare_statement_key_columns: JMethodID,
+    prepare_call: JMethodID,
+    create_statement: JMethodID,
+    set_auto_commit: JMethodID,
+    get_auto_commit: JMethodID,
+    commit: JMethodID,
+    rollback: JMethodID,
+    set_savepoint: JMethodID,
+    set_savepoint_named: JMethodID,
+    rollback_savepoint: JMethodID,
+    release_savepoint: JMethodID,
+    set_transaction_isolation: JMethodID,
+    get_transaction_isolation: JMethodID,
+    set_read_only: JMethodID,
+    is_read_only: JMethodID,
+    create_array_of: JMethodID,
+    create_blob: JMethodID,
+    create_clob: JMethodID,
+    get_meta_data: JMethodID,
+    product_name: OnceCell<String>,
+    transaction_depth: Cell<u32>,
+    statement_options: StatementOptions,
 }
 
 impl<'local> Connection<'local> {
@@ -30,33 +61,431 @@ impl<'local> Connection<'local> {
             "prepareStatement",
             "(Ljava/lang/String;)Ljava/sql/PreparedStatement;",
         )?;
+        let prepare_statement_auto_keys = env.get_method_id(
+            &class,
+            "prepareStatement",
+            "(Ljava/lang/String;I)Ljava/sql/PreparedStatement;",
+        )?;
+        let prepare_statement_key_columns = env.get_method_id(
+            &class,
+            "prepareStatement",
+            "(Ljava/lang/String;[Ljava/lang/String;)Ljava/sql/PreparedStatement;",
+        )?;
+        let prepare_call = env.get_method_id(
+            &class,
+            "prepareCall",
+            "(Ljava/lang/String;)Ljava/sql/CallableStatement;",
+        )?;
+        let create_statement =
+            env.get_method_id(&class, "createStatement", "()Ljava/sql/Statement;")?;
+        let set_auto_commit = env.get_method_id(&class, "setAutoCommit", "(Z)V")?;
+        let get_auto_commit = env.get_method_id(&class, "getAutoCommit", "()Z")?;
+        let commit = env.get_method_id(&class, "commit", "()V")?;
+        let rollback = env.get_method_id(&class, "rollback", "()V")?;
+        let set_savepoint = env.get_method_id(&class, "setSavepoint", "()Ljava/sql/Savepoint;")?;
+        let set_savepoint_named = env.get_method_id(
+            &class,
+            "setSavepoint",
+            "(Ljava/lang/String;)Ljava/sql/Savepoint;",
+        )?;
+        let rollback_savepoint =
+            env.get_method_id(&class, "rollback", "(Ljava/sql/Savepoint;)V")?;
+        let release_savepoint =
+            env.get_method_id(&class, "releaseSavepoint", "(Ljava/sql/Savepoint;)V")?;
+        let set_transaction_isolation =
+            env.get_method_id(&class, "setTransactionIsolation", "(I)V")?;
+        let get_transaction_isolation =
+            env.get_method_id(&class, "getTransactionIsolation", "()I")?;
+        let set_read_only = env.get_method_id(&class, "setReadOnly", "(Z)V")?;
+        let is_read_only = env.get_method_id(&class, "isReadOnly", "()Z")?;
+        let create_array_of = env.get_method_id(
+            &class,
+            "createArrayOf",
+            "(Ljava/lang/String;[Ljava/lang/Object;)Ljava/sql/Array;",
+        )?;
+        let create_blob = env.get_method_id(&class, "createBlob", "()Ljava/sql/Blob;")?;
+        let create_clob = env.get_method_id(&class, "createClob", "()Ljava/sql/Clob;")?;
+        let get_meta_data =
+            env.get_method_id(&class, "getMetaData", "()Ljava/sql/DatabaseMetaData;")?;
 
         Ok(Connection {
             inner: datasource,
             guard,
             prepare_statement,
+            prepare_statement_auto_keys,
+            prepare_statement_key_columns,
+            prepare_call,
+            create_statement,
+            set_auto_commit,
+            get_auto_commit,
+            commit,
+            rollback,
+            set_savepoint,
+            set_savepoint_named,
+            rollback_savepoint,
+            release_savepoint,
+            set_transaction_isolation,
+            get_transaction_isolation,
+            set_read_only,
+            is_read_only,
+            create_array_of,
+            create_blob,
+            create_clob,
+            get_meta_data,
+            product_name: OnceCell::new(),
+            transaction_depth: Cell::new(0),
+            statement_options: StatementOptions::default(),
         })
     }
 
     pub fn prepare_statement<'parent>(
         &'parent self,
         sql: &str,
+    ) -> Result<PreparedStatement<'parent>, Error> {
+        self.prepare(self.prepare_statement, sql, &[])
+    }
+
+    /// Prepares a statement with `:name` / `@name` parameters, see [`NamedSql`].
+    pub fn prepare_named<'parent>(
+        &'parent self,
+        sql: &str,
+    ) -> Result<NamedStatement<'parent>, Error> {
+        let sql = NamedSql::parse(sql);
+        let statement = self.prepare_statement(sql.sql())?;
+        Ok(NamedStatement::new(statement, sql))
+    }
+
+    /// Prepares a statement with `Statement.RETURN_GENERATED_KEYS`,
+    /// read the keys with [`PreparedStatement::generated_keys`].
+    pub fn prepare_statement_returning_keys<'parent>(
+        &'parent self,
+        sql: &str,
+    ) -> Result<PreparedStatement<'parent>, Error> {
+        // Statement.RETURN_GENERATED_KEYS
+        self.prepare(self.prepare_statement_auto_keys, sql, &[jvalue { i: 1 }])
+    }
+
+    /// Prepares a statement returning the given columns as generated keys.
+    pub fn prepare_statement_with_key_columns<'parent>(
+        &'parent self,
+        sql: &str,
+        columns: &[&str],
     ) -> Result<PreparedStatement<'parent>, Error> {
+        let mut env = unsafe { self.env() };
+        let array =
+            env.new_object_array(columns.len() as i32, "java/lang/String", JObject::null())?;
+        for (i, column) in columns.iter().enumerate() {
+            let column = env.new_string(column)?;
+            env.set_object_array_element(&array, i as i32, &column)?;
+            env.delete_local_ref(column)?;
+        }
+        let array = JObject::from(array);
+        let statement = self.prepare(
+            self.prepare_statement_key_columns,
+            sql,
+            &[JValueGen::Object(&array).as_jni()],
+        );
+        env.delete_local_ref(array)?;
+        statement
+    }
+
+    /// Runs an update with the given parameters, returns the update count.
+    pub fn execute<P: Params>(&self, sql: &str, params: P) -> Result<i32, Error> {
+        params.bind(self.prepare_statement(sql)?)?.execute_update()
+    }
+
+    /// Runs a query with the given parameters, e.g. `conn.query(sql, (1, "Tom"))?`.
+    pub fn query<'parent, P: Params>(
+        &'parent self,
+        sql: &str,
+        params: P,
+    ) -> Result<Rows<'parent>, Error> {
+        Rows::new(params.bind(self.prepare_statement(sql)?)?)
+    }
+
+    /// Reads the first row, fails with [`Error::NoRows`] if there is none.
+    pub fn query_one<T: FromRow, P: Params>(&self, sql: &str, params: P) -> Result<T, Error> {
+        self.query_opt(sql, params)?.ok_or(Error::NoRows)
+    }
+
+    /// Reads the first row if there is one.
+    pub fn query_opt<T: FromRow, P: Params>(
+        &self,
+        sql: &str,
+        params: P,
+    ) -> Result<Option<T>, Error> {
+        let rows = self.query(sql, params)?;
+        if !rows.next()? {
+            return Ok(None);
+        }
+        T::from_row(&rows).map(Some)
+    }
+
+    /// Reads the first column of the first row, e.g. `select count(*) ...`.
+    pub fn query_scalar<T: FromSql, P: Params>(&self, sql: &str, params: P) -> Result<T, Error> {
+        self.query_one::<(T,), P>(sql, params).map(|(value,)| value)
+    }
+
+    /// Defaults applied to every statement created from this connection.
+    pub fn set_statement_options(&mut self, options: StatementOptions) {
+        self.statement_options = options;
+    }
+
+    pub fn statement_options(&self) -> &StatementOptions {
+        &self.statement_options
+    }
+
+    pub fn create_statement<'parent>(&'parent self) -> Result<Statement<'parent>, Error> {
+        let mut env = unsafe { self.env() };
+        let statement = unsafe {
+            env.call_method_unchecked(&self.inner, self.create_statement, ReturnType::Object, &[])
+        };
+        let statement = util::exception::catch(&mut env, statement)?;
+        if let JValueGen::Object(statement) = statement {
+            return Statement::from_ref(self, statement)?.apply_options(&self.statement_options);
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    /// Prepares a stored procedure call, e.g. `{? = call my_function(?)}`.
+    pub fn prepare_call<'parent>(
+        &'parent self,
+        sql: &str,
+    ) -> Result<CallableStatement<'parent>, Error> {
         let mut env = unsafe { self.env() };
         let sql: JObject<'_> = env.new_string(sql)?.into();
         let statement = unsafe {
             env.call_method_unchecked(
                 &self.inner,
-                self.prepare_statement,
+                self.prepare_call,
                 ReturnType::Object,
                 &[JValueGen::Object(&sql).as_jni()],
-            )?
+            )
+        };
+        env.delete_local_ref(sql)?;
+        let statement = util::exception::catch(&mut env, statement)?;
+        if let JValueGen::Object(statement) = statement {
+            return CallableStatement::from_ref(self, statement)?
+                .apply_options(&self.statement_options);
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    fn prepare<'parent>(
+        &'parent self,
+        method: JMethodID,
+        sql: &str,
+        args: &[jvalue],
+    ) -> Result<PreparedStatement<'parent>, Error> {
+        let mut env = unsafe { self.env() };
+        let sql: JObject<'_> = env.new_string(sql)?.into();
+        let mut all_args = vec![JValueGen::Object(&sql).as_jni()];
+        all_args.extend_from_slice(args);
+        let statement = unsafe {
+            env.call_method_unchecked(&self.inner, method, ReturnType::Object, &all_args)
         };
         env.delete_local_ref(sql)?;
+        let statement = util::exception::catch(&mut env, statement)?;
         if let JValueGen::Object(statement) = statement {
-            return Ok(PreparedStatement::from_ref(self, statement)?);
+            return PreparedStatement::from_ref(self, statement)?
+                .apply_options(&self.statement_options);
         }
-        return Err(Error::ImpossibleError);
+        Err(Error::ImpossibleError)
+    }
+
+    pub fn set_auto_commit(&self, auto_commit: bool) -> Result<(), Error> {
+        let mut env = unsafe { self.env() };
+        let value = util::cast::bool_to_jvalue(auto_commit);
+        util::call::void(&mut env, &self.inner, &self.set_auto_commit, &[value])
+    }
+
+    pub fn get_auto_commit(&self) -> Result<bool, Error> {
+        let mut env = unsafe { self.env() };
+        util::call::get_bool(&mut env, &self.inner, &self.get_auto_commit)
+    }
+
+    pub fn commit(&self) -> Result<(), Error> {
+        let mut env = unsafe { self.env() };
+        util::call::void(&mut env, &self.inner, &self.commit, &[])
+    }
+
+    pub fn rollback(&self) -> Result<(), Error> {
+        let mut env = unsafe { self.env() };
+        util::call::void(&mut env, &self.inner, &self.rollback, &[])
+    }
+
+    pub fn set_savepoint<'parent>(&'parent self) -> Result<Savepoint<'parent>, Error> {
+        let mut env = unsafe { self.env() };
+        let savepoint = unsafe {
+            env.call_method_unchecked(&self.inner, self.set_savepoint, ReturnType::Object, &[])
+        };
+        let savepoint = util::exception::catch(&mut env, savepoint)?;
+        if let JValueGen::Object(savepoint) = savepoint {
+            return Ok(Savepoint::from_ref(self, savepoint));
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    pub fn set_savepoint_named<'parent>(
+        &'parent self,
+        name: &str,
+    ) -> Result<Savepoint<'parent>, Error> {
+        let mut env = unsafe { self.env() };
+        let name: JObject<'_> = env.new_string(name)?.into();
+        let savepoint = unsafe {
+            env.call_method_unchecked(
+                &self.inner,
+                self.set_savepoint_named,
+                ReturnType::Object,
+                &[JValueGen::Object(&name).as_jni()],
+            )
+        };
+        env.delete_local_ref(name)?;
+        let savepoint = util::exception::catch(&mut env, savepoint)?;
+        if let JValueGen::Object(savepoint) = savepoint {
+            return Ok(Savepoint::from_ref(self, savepoint));
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    /// Undoes all changes made after the savepoint was set.
+    pub fn rollback_to_savepoint(&self, savepoint: &Savepoint<'_>) -> Result<(), Error> {
+        let mut env = unsafe { self.env() };
+        let savepoint = JValueGen::Object(savepoint.as_obj()).as_jni();
+        util::call::void(
+            &mut env,
+            &self.inner,
+            &self.rollback_savepoint,
+            &[savepoint],
+        )
+    }
+
+    pub fn release_savepoint(&self, savepoint: Savepoint<'_>) -> Result<(), Error> {
+        let mut env = unsafe { self.env() };
+        let savepoint = JValueGen::Object(savepoint.as_obj()).as_jni();
+        util::call::void(&mut env, &self.inner, &self.release_savepoint, &[savepoint])
+    }
+
+    /// An empty `Blob` to fill with [`Blob::binary_writer`].
+    pub fn create_blob<'parent>(&'parent self) -> Result<Blob<'parent>, Error> {
+        let mut env = unsafe { self.env() };
+        let blob = unsafe {
+            env.call_method_unchecked(&self.inner, self.create_blob, ReturnType::Object, &[])
+        };
+        let blob = util::exception::catch(&mut env, blob)?.l()?;
+        Ok(Blob::from_ref(self, blob))
+    }
+
+    /// An empty `Clob` to fill with [`Clob::character_writer`].
+    pub fn create_clob<'parent>(&'parent self) -> Result<Clob<'parent>, Error> {
+        let mut env = unsafe { self.env() };
+        let clob = unsafe {
+            env.call_method_unchecked(&self.inner, self.create_clob, ReturnType::Object, &[])
+        };
+        let clob = util::exception::catch(&mut env, clob)?.l()?;
+        Ok(Clob::from_ref(self, clob))
+    }
+
+    pub fn set_transaction_isolation(&self, level: IsolationLevel) -> Result<(), Error> {
+        self.set_transaction_isolation_raw(level.into())
+    }
+
+    /// `None` for `TRANSACTION_NONE` or a driver specific level.
+    pub fn get_transaction_isolation(&self) -> Result<Option<IsolationLevel>, Error> {
+        let level = self.get_transaction_isolation_raw()?;
+        Ok(IsolationLevel::from_i32(level))
+    }
+
+    /// `setTransactionIsolation` with any level, including driver specific ones.
+    pub(crate) fn set_transaction_isolation_raw(&self, level: i32) -> Result<(), Error> {
+        let mut env = unsafe { self.env() };
+        util::call::void(
+            &mut env,
+            &self.inner,
+            &self.set_transaction_isolation,
+            &[jvalue { i: level }],
+        )
+    }
+
+    pub(crate) fn get_transaction_isolation_raw(&self) -> Result<i32, Error> {
+        let mut env = unsafe { self.env() };
+        util::call::get_int(&mut env, &self.inner, &self.get_transaction_isolation)
+    }
+
+    pub fn set_read_only(&self, read_only: bool) -> Result<(), Error> {
+        let mut env = unsafe { self.env() };
+        let value = util::cast::bool_to_jvalue(read_only);
+        util::call::void(&mut env, &self.inner, &self.set_read_only, &[value])
+    }
+
+    pub fn is_read_only(&self) -> Result<bool, Error> {
+        let mut env = unsafe { self.env() };
+        util::call::get_bool(&mut env, &self.inner, &self.is_read_only)
+    }
+
+    /// Number of open [`Transaction`] guards on this connection.
+    pub fn transaction_depth(&self) -> u32 {
+        self.transaction_depth.get()
+    }
+
+    pub(crate) fn set_transaction_depth(&self, depth: u32) {
+        self.transaction_depth.set(depth);
+    }
+
+    /// Begins a transaction, it is rolled back on drop unless committed.
+    ///
+    /// Inside another transaction it begins a nested transaction backed by a savepoint,
+    /// committing it releases the savepoint and rolling it back only undoes its own changes.
+    pub fn transaction<'conn>(&'conn self) -> Result<Transaction<'conn, 'local>, Error> {
+        self.transaction_builder().begin()
+    }
+
+    /// Runs `f` in a transaction, commits on `Ok` and rolls back on `Err` or panic.
+    pub fn in_transaction<T, E, F>(&self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&Transaction<'_, 'local>) -> Result<T, E>,
+        E: From<Error>,
+    {
+        self.transaction_builder().in_transaction(f)
+    }
+
+    /// Configures the isolation level and read-only mode of a transaction.
+    pub fn transaction_builder<'conn>(&'conn self) -> TransactionBuilder<'conn, 'local> {
+        TransactionBuilder::new(self)
+    }
+
+    /// `DatabaseMetaData.getDatabaseProductName()`, e.g. `PostgreSQL` or `H2`, read once.
+    pub fn database_product_name(&self) -> Result<&str, Error> {
+        if let Some(name) = self.product_name.get() {
+            return Ok(name);
+        }
+        let mut env = unsafe { self.env() };
+        let meta = unsafe {
+            env.call_method_unchecked(&self.inner, self.get_meta_data, ReturnType::Object, &[])
+        };
+        let meta = AutoLocal::new(util::exception::catch(&mut env, meta)?.l()?, &env);
+        let name = env.call_method(&meta, "getDatabaseProductName", "()Ljava/lang/String;", &[]);
+        let name = util::exception::catch(&mut env, name)?;
+        let name = util::cast::value_cast_string(&mut env, name)?;
+        Ok(self.product_name.get_or_init(|| name))
+    }
+
+    /// `createArrayOf(type_name, elements)`, the type name is database specific.
+    pub(crate) fn create_array_of<'a>(
+        &self,
+        env: &mut JNIEnv<'a>,
+        type_name: &str,
+        elements: &JObjectArray<'a>,
+    ) -> Result<JObject<'a>, Error> {
+        let type_name = AutoLocal::new(env.new_string(type_name)?, env);
+        let args = [
+            JValueGen::Object(&**type_name).as_jni(),
+            JValueGen::Object(&**elements).as_jni(),
+        ];
+        let array = unsafe {
+            env.call_method_unchecked(&self.inner, self.create_array_of, ReturnType::Object, &args)
+        };
+        Ok(util::exception::catch(env, array)?.l()?)
     }
 
     pub unsafe fn env(&self) -> JNIEnv {
@@ -0,0 +1,378 @@
+use std::fmt::Display;
+
+use crate::errors::Error;
+
+use super::{ArrayElement, Blob, Clob, PreparedStatement, ResultSet, SqlType};
+
+/// A column of a [`ResultSet`], by 1-based index or by label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column<'a> {
+    Index(i32),
+    Label(&'a str),
+}
+
+impl From<i32> for Column<'_> {
+    fn from(index: i32) -> Self {
+        Column::Index(index)
+    }
+}
+
+impl<'a> From<&'a str> for Column<'a> {
+    fn from(label: &'a str) -> Self {
+        Column::Label(label)
+    }
+}
+
+impl<'a> From<&'a String> for Column<'a> {
+    fn from(label: &'a String) -> Self {
+        Column::Label(label)
+    }
+}
+
+/// A string parameter of [`PreparedStatement::set_string`] and the like, `None` binds `NULL`.
+///
+/// Implemented for `&str`, `&String` and `Option<&str>`, a bare `None` infers the latter.
+pub trait StrParam<'a> {
+    fn into_str(self) -> Option<&'a str>;
+}
+
+impl<'a, T: AsRef<str> + ?Sized> StrParam<'a> for &'a T {
+    fn into_str(self) -> Option<&'a str> {
+        Some(self.as_ref())
+    }
+}
+
+impl<'a> StrParam<'a> for Option<&'a str> {
+    fn into_str(self) -> Option<&'a str> {
+        self
+    }
+}
+
+impl Display for Column<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Column::Index(index) => index.fmt(f),
+            Column::Label(label) => label.fmt(f),
+        }
+    }
+}
+
+/// A value bound as a statement parameter with [`PreparedStatement::bind`].
+///
+/// Implement it for newtypes by delegating to the wrapped value:
+///
+/// ```ignore
+/// impl ToSql for UserId {
+///     fn to_sql<'local>(
+///         &self,
+///         statement: PreparedStatement<'local>,
+///         index: i32,
+///     ) -> Result<PreparedStatement<'local>, Error> {
+///         self.0.to_sql(statement, index)
+///     }
+/// }
+/// ```
+pub trait ToSql {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error>;
+
+    /// Type of the `NULL` bound for `None::<Self>`.
+    fn sql_type() -> SqlType
+    where
+        Self: Sized,
+    {
+        SqlType::Null
+    }
+}
+
+/// A value read from a column with [`ResultSet::get`].
+///
+/// Implement it for newtypes by mapping the wrapped value:
+///
+/// ```ignore
+/// impl FromSql for UserId {
+///     fn from_sql(result: &ResultSet<'_>, column: Column<'_>) -> Result<Option<Self>, Error> {
+///         Ok(i64::from_sql(result, column)?.map(UserId))
+///     }
+/// }
+/// ```
+pub trait FromSql: Sized {
+    /// `None` if the column is SQL `NULL`.
+    fn from_sql(result: &ResultSet<'_>, column: Column<'_>) -> Result<Option<Self>, Error>;
+}
+
+macro_rules! to_sql {
+    ($($type:ty => $setter:ident, $sql_type:expr;)*) => {
+        $(
+            impl ToSql for $type {
+                fn to_sql<'local>(
+                    &self,
+                    statement: PreparedStatement<'local>,
+                    index: i32,
+                ) -> Result<PreparedStatement<'local>, Error> {
+                    statement.$setter(index, *self)
+                }
+
+                fn sql_type() -> SqlType {
+                    $sql_type
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! from_sql {
+    ($($type:ty => $get:ident, $get_by_label:ident;)*) => {
+        $(
+            impl FromSql for $type {
+                fn from_sql(result: &ResultSet<'_>, column: Column<'_>) -> Result<Option<Self>, Error> {
+                    match column {
+                        Column::Index(index) => result.$get(index),
+                        Column::Label(label) => result.$get_by_label(label),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+to_sql! {
+    char => set_char, SqlType::Char;
+    i8 => set_byte, SqlType::TinyInt;
+    i16 => set_short, SqlType::SmallInt;
+    i32 => set_int, SqlType::Integer;
+    i64 => set_long, SqlType::BigInt;
+    f32 => set_float, SqlType::Real;
+    f64 => set_double, SqlType::Double;
+    bool => set_boolean, SqlType::Boolean;
+}
+
+from_sql! {
+    char => get_char, get_char_by_label;
+    i8 => get_byte, get_byte_by_label;
+    i16 => get_short, get_short_by_label;
+    i32 => get_int, get_int_by_label;
+    i64 => get_long, get_long_by_label;
+    f32 => get_float, get_float_by_label;
+    f64 => get_double, get_double_by_label;
+    bool => get_boolean, get_boolean_by_label;
+    String => get_string, get_string_by_label;
+    Vec<u8> => get_bytes, get_bytes_by_label;
+}
+
+#[cfg(feature = "chrono")]
+from_sql! {
+    chrono::DateTime<chrono::Utc> => get_utc_time, get_utc_time_by_label;
+    chrono::DateTime<chrono::Local> => get_local_time, get_local_time_by_label;
+    chrono::NaiveDate => get_naive_date, get_naive_date_by_label;
+    chrono::NaiveTime => get_naive_time, get_naive_time_by_label;
+    chrono::NaiveDateTime => get_naive_date_time, get_naive_date_time_by_label;
+    chrono::DateTime<chrono::FixedOffset> => get_offset_date_time, get_offset_date_time_by_label;
+}
+
+#[cfg(feature = "chrono")]
+to_sql! {
+    chrono::NaiveDate => set_naive_date, SqlType::Date;
+    chrono::NaiveTime => set_naive_time, SqlType::Time;
+    chrono::NaiveDateTime => set_naive_date_time, SqlType::Timestamp;
+    chrono::DateTime<chrono::FixedOffset> => set_offset_date_time, SqlType::TimestampWithTimezone;
+}
+
+#[cfg(feature = "rust_decimal")]
+from_sql! {
+    rust_decimal::Decimal => get_decimal, get_decimal_by_label;
+}
+
+#[cfg(feature = "rust_decimal")]
+to_sql! {
+    rust_decimal::Decimal => set_decimal, SqlType::Decimal;
+}
+
+#[cfg(feature = "time")]
+from_sql! {
+    time::Date => get_time_date, get_time_date_by_label;
+    time::Time => get_time_time, get_time_time_by_label;
+    time::PrimitiveDateTime => get_time_primitive_date_time, get_time_primitive_date_time_by_label;
+    time::OffsetDateTime => get_time_offset_date_time, get_time_offset_date_time_by_label;
+}
+
+#[cfg(feature = "time")]
+to_sql! {
+    time::Date => set_time_date, SqlType::Date;
+    time::Time => set_time_time, SqlType::Time;
+    time::PrimitiveDateTime => set_time_primitive_date_time, SqlType::Timestamp;
+    time::OffsetDateTime => set_time_offset_date_time, SqlType::TimestampWithTimezone;
+}
+
+#[cfg(feature = "uuid")]
+from_sql! {
+    uuid::Uuid => get_uuid, get_uuid_by_label;
+}
+
+#[cfg(feature = "uuid")]
+to_sql! {
+    uuid::Uuid => set_uuid, SqlType::Other;
+}
+
+#[cfg(feature = "serde_json")]
+from_sql! {
+    serde_json::Value => get_json, get_json_by_label;
+}
+
+#[cfg(feature = "serde_json")]
+impl ToSql for serde_json::Value {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        // JSON `null` binds SQL `NULL`, like `None`.
+        match self {
+            serde_json::Value::Null => statement.set_null(index, SqlType::Other),
+            value => statement.set_json(index, value),
+        }
+    }
+
+    fn sql_type() -> SqlType {
+        SqlType::Other
+    }
+}
+
+impl ToSql for str {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        statement.set_string(index, self)
+    }
+}
+
+impl ToSql for &str {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        statement.set_string(index, *self)
+    }
+
+    fn sql_type() -> SqlType {
+        SqlType::VarChar
+    }
+}
+
+impl ToSql for String {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        statement.set_string(index, self.as_str())
+    }
+
+    fn sql_type() -> SqlType {
+        SqlType::VarChar
+    }
+}
+
+impl ToSql for [u8] {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        statement.set_bytes(index, self)
+    }
+}
+
+impl ToSql for &[u8] {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        statement.set_bytes(index, *self)
+    }
+
+    fn sql_type() -> SqlType {
+        SqlType::VarBinary
+    }
+}
+
+impl ToSql for Vec<u8> {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        statement.set_bytes(index, self.as_slice())
+    }
+
+    fn sql_type() -> SqlType {
+        SqlType::VarBinary
+    }
+}
+
+impl ToSql for Blob<'_> {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        statement.set_blob_value(index, self)
+    }
+
+    fn sql_type() -> SqlType {
+        SqlType::Blob
+    }
+}
+
+impl ToSql for Clob<'_> {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        statement.set_clob_value(index, self)
+    }
+
+    fn sql_type() -> SqlType {
+        SqlType::Clob
+    }
+}
+
+impl<T: ToSql> ToSql for Option<T> {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        match self {
+            Some(value) => value.to_sql(statement, index),
+            None => statement.set_null(index, T::sql_type()),
+        }
+    }
+
+    fn sql_type() -> SqlType {
+        T::sql_type()
+    }
+}
+
+impl<T: ArrayElement> FromSql for Vec<Option<T>> {
+    fn from_sql(result: &ResultSet<'_>, column: Column<'_>) -> Result<Option<Self>, Error> {
+        match column {
+            Column::Index(index) => result.get_array(index),
+            Column::Label(label) => result.get_array_by_label(label),
+        }
+    }
+}
+
+impl<T: FromSql> FromSql for Option<T> {
+    fn from_sql(result: &ResultSet<'_>, column: Column<'_>) -> Result<Option<Self>, Error> {
+        Ok(Some(T::from_sql(result, column)?))
+    }
+}
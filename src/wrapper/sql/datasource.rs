@@ -1,5 +1,5 @@
 use super::connection::Connection;
-use crate::errors::Error;
+use crate::{errors::Error, util};
 use jni::{
     objects::{AutoLocal, JMethodID, JObject, JValueGen},
     signature::ReturnType,
@@ -34,7 +34,8 @@ impl<'local, 'obj_ref> DataSource<'local, 'obj_ref> {
         let conn = unsafe {
             self.env
                 .call_method_unchecked(&self.inner, self.get_conn, ReturnType::Object, &[])
-        }?;
+        };
+        let conn = util::exception::catch(&mut self.env, conn)?;
 
         if let JValueGen::Object(obj) = conn {
             return Ok(Connection::from_ref(guard, obj)?);
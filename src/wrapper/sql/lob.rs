@@ -0,0 +1,76 @@
+use jni::{
+    objects::{AutoLocal, JObject},
+    JNIEnv,
+};
+
+use crate::{errors::Error, util, Connection};
+
+use super::{BinaryWriter, CharacterWriter};
+
+/// `java.sql.Blob`, created empty by [`Connection::create_blob`] and bound with
+/// [`PreparedStatement::bind`](super::PreparedStatement::bind).
+pub struct Blob<'local> {
+    inner: AutoLocal<'local, JObject<'local>>,
+    env: JNIEnv<'local>,
+}
+
+impl<'local> Blob<'local> {
+    pub fn from_ref(conn: &'local Connection<'local>, blob: JObject<'local>) -> Self {
+        let env = unsafe { conn.env() };
+        Blob {
+            inner: AutoLocal::new(blob, &env),
+            env,
+        }
+    }
+
+    /// `setBinaryStream(1)`, drop the writer before binding the blob, some drivers only
+    /// store the value when the stream is closed.
+    pub fn binary_writer(&mut self) -> Result<BinaryWriter<'local>, Error> {
+        let stream = self.env.call_method(
+            &self.inner,
+            "setBinaryStream",
+            "(J)Ljava/io/OutputStream;",
+            &[1i64.into()],
+        );
+        let stream = util::exception::catch(&mut self.env, stream)?.l()?;
+        BinaryWriter::from_ref(&mut self.env, stream)
+    }
+
+    pub(crate) fn as_obj(&self) -> &JObject<'local> {
+        &self.inner
+    }
+}
+
+/// `java.sql.Clob`, created empty by [`Connection::create_clob`] and bound with
+/// [`PreparedStatement::bind`](super::PreparedStatement::bind).
+pub struct Clob<'local> {
+    inner: AutoLocal<'local, JObject<'local>>,
+    env: JNIEnv<'local>,
+}
+
+impl<'local> Clob<'local> {
+    pub fn from_ref(conn: &'local Connection<'local>, clob: JObject<'local>) -> Self {
+        let env = unsafe { conn.env() };
+        Clob {
+            inner: AutoLocal::new(clob, &env),
+            env,
+        }
+    }
+
+    /// `setCharacterStream(1)`, drop the writer before binding the clob as with
+    /// [`Blob::binary_writer`].
+    pub fn character_writer(&mut self) -> Result<CharacterWriter<'local>, Error> {
+        let writer = self.env.call_method(
+            &self.inner,
+            "setCharacterStream",
+            "(J)Ljava/io/Writer;",
+            &[1i64.into()],
+        );
+        let writer = util::exception::catch(&mut self.env, writer)?.l()?;
+        CharacterWriter::from_ref(&mut self.env, writer)
+    }
+
+    pub(crate) fn as_obj(&self) -> &JObject<'local> {
+        &self.inner
+    }
+}
@@ -1,13 +1,48 @@
 // java.sql.*
 
+/// Delegates the consuming setters to the wrapped [`PreparedStatement`].
+macro_rules! delegate {
+    ($($(#[$meta:meta])* $name:ident$(<$lt:lifetime>)?($($arg:ident: $type:ty),*);)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name$(<$lt>)?(mut self, $($arg: $type),*) -> Result<Self, Error> {
+                self.statement = self.statement.$name($($arg),*)?;
+                Ok(self)
+            }
+        )*
+    };
+}
+
+mod array;
+mod callable;
+mod cancel;
 mod connection;
+mod convert;
 mod datasource;
+mod lob;
+mod named;
+mod params;
 mod result;
 mod result_meta;
+mod savepoint;
 mod statement;
+mod stream;
+mod transaction;
+mod types;
 
+pub use array::*;
+pub use callable::*;
+pub use cancel::*;
 pub use connection::*;
+pub use convert::*;
 pub use datasource::*;
+pub use lob::*;
+pub use named::*;
+pub use params::*;
 pub use result::*;
 pub use result_meta::*;
+pub use savepoint::*;
 pub use statement::*;
+pub use stream::*;
+pub use transaction::*;
+pub use types::*;
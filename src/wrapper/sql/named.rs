@@ -0,0 +1,395 @@
+use std::{
+    io::Read,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use crate::errors::Error;
+
+use super::{
+    ArrayElement, ArrayParam, FetchDirection, PreparedStatement, SqlType, StrParam, ToSql,
+};
+
+/// SQL with `:name` / `@name` parameters rewritten to positional `?`.
+///
+/// String literals, quoted identifiers, comments, dollar quoted strings and `::` casts
+/// are left untouched. A `?` already in the SQL stays a positional parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedSql {
+    sql: String,
+    /// Name of every `?` in order, `None` for positional ones.
+    params: Vec<Option<String>>,
+}
+
+impl NamedSql {
+    pub fn parse(sql: &str) -> Self {
+        let chars: Vec<char> = sql.chars().collect();
+        let mut rewritten = String::with_capacity(sql.len());
+        let mut params = Vec::new();
+
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+            let after_ident = i > 0 && is_ident(chars[i - 1]);
+            let end = match (c, next) {
+                ('\'' | '"' | '`', _) => skip_quoted(&chars, i),
+                ('-', Some('-')) => skip_line_comment(&chars, i),
+                ('/', Some('*')) => skip_block_comment(&chars, i),
+                ('$', _) if !after_ident => skip_dollar_quoted(&chars, i),
+                (':', Some(':')) | ('@', Some('@')) | ('?', Some('?')) => i + 2,
+                (':' | '@', Some(next)) if !after_ident && is_ident_start(next) => {
+                    let mut end = i + 1;
+                    while end < chars.len() && is_ident(chars[end]) {
+                        end += 1;
+                    }
+                    params.push(Some(chars[i + 1..end].iter().collect()));
+                    rewritten.push('?');
+                    i = end;
+                    continue;
+                }
+                ('?', _) => {
+                    params.push(None);
+                    i + 1
+                }
+                _ => i + 1,
+            };
+            rewritten.extend(&chars[i..end]);
+            i = end;
+        }
+
+        NamedSql {
+            sql: rewritten,
+            params,
+        }
+    }
+
+    /// The SQL passed to `Connection.prepareStatement`.
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Number of `?` in the rewritten SQL.
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Distinct parameter names in order of first appearance.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for name in self.params.iter().flatten() {
+            if !names.contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// 1-based positions of the parameter, it may appear more than once.
+    pub fn indices(&self, name: &str) -> Result<Vec<i32>, Error> {
+        let indices: Vec<i32> = self
+            .params
+            .iter()
+            .enumerate()
+            .filter(|(_, param)| param.as_deref() == Some(name))
+            .map(|(i, _)| i as i32 + 1)
+            .collect();
+        if indices.is_empty() {
+            return Err(Error::UnknownParameter(name.to_string()));
+        }
+        Ok(indices)
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// `'...'`, `"..."` or `` `...` ``, a doubled quote is an escaped one.
+///
+/// A backslash is an ordinary character, as in standard SQL.
+fn skip_quoted(chars: &[char], start: usize) -> usize {
+    let quote = chars[start];
+    let mut i = start + 1;
+    while i < chars.len() {
+        if chars[i] == quote {
+            if chars.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    chars.len()
+}
+
+fn skip_line_comment(chars: &[char], start: usize) -> usize {
+    chars[start..]
+        .iter()
+        .position(|&c| c == '\n')
+        .map_or(chars.len(), |end| start + end + 1)
+}
+
+fn skip_block_comment(chars: &[char], start: usize) -> usize {
+    chars[start + 2..]
+        .windows(2)
+        .position(|w| w == ['*', '/'])
+        .map_or(chars.len(), |end| start + 2 + end + 2)
+}
+
+/// PostgreSQL `$$...$$` or `$tag$...$tag$`, anything else is a single `$`.
+fn skip_dollar_quoted(chars: &[char], start: usize) -> usize {
+    let mut i = start + 1;
+    if chars
+        .get(i)
+        .is_some_and(|&c| !is_ident_start(c) && c != '$')
+    {
+        return start + 1;
+    }
+    while i < chars.len() && is_ident(chars[i]) {
+        i += 1;
+    }
+    if chars.get(i) != Some(&'$') {
+        return start + 1;
+    }
+    let tag = &chars[start..=i];
+    chars[i + 1..]
+        .windows(tag.len())
+        .position(|w| w == tag)
+        .map_or(chars.len(), |end| i + 1 + end + tag.len())
+}
+
+/// Sets the consuming named setters on every position of the parameter.
+macro_rules! named {
+    ($($(#[$meta:meta])* $name:ident$(<$lt:lifetime>)? => $setter:ident($type:ty);)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name$(<$lt>)?(mut self, name: &str, value: impl Into<Option<$type>>) -> Result<Self, Error> {
+                let value = value.into();
+                for index in self.sql.indices(name)? {
+                    self.statement = self.statement.$setter(index, value)?;
+                }
+                Ok(self)
+            }
+        )*
+    };
+}
+
+/// A [`PreparedStatement`] with named parameters, created by
+/// [`Connection::prepare_named`](super::Connection::prepare_named).
+pub struct NamedStatement<'local> {
+    statement: PreparedStatement<'local>,
+    sql: NamedSql,
+}
+
+impl<'local> NamedStatement<'local> {
+    /// `statement` must be prepared from [`NamedSql::sql`].
+    pub fn new(statement: PreparedStatement<'local>, sql: NamedSql) -> Self {
+        NamedStatement { statement, sql }
+    }
+
+    pub fn named_sql(&self) -> &NamedSql {
+        &self.sql
+    }
+
+    pub fn set_null_named(mut self, name: &str, sql_type: SqlType) -> Result<Self, Error> {
+        for index in self.sql.indices(name)? {
+            self.statement = self.statement.set_null(index, sql_type)?;
+        }
+        Ok(self)
+    }
+
+    /// Binds any [`ToSql`] value on every position of the parameter.
+    pub fn bind_named<T: ToSql + ?Sized>(mut self, name: &str, value: &T) -> Result<Self, Error> {
+        for index in self.sql.indices(name)? {
+            self.statement = self.statement.bind(index, value)?;
+        }
+        Ok(self)
+    }
+
+    pub fn bind<T: ToSql + ?Sized>(mut self, index: i32, value: &T) -> Result<Self, Error> {
+        self.statement = self.statement.bind(index, value)?;
+        Ok(self)
+    }
+
+    pub fn set_array_named<'a, T: ArrayElement + 'a>(
+        mut self,
+        name: &str,
+        type_name: &str,
+        values: impl ArrayParam<'a, T>,
+    ) -> Result<Self, Error> {
+        let values = values.into_slice();
+        for index in self.sql.indices(name)? {
+            self.statement = self.statement.set_array(index, type_name, values)?;
+        }
+        Ok(self)
+    }
+
+    pub fn set_array<'a, T: ArrayElement + 'a>(
+        mut self,
+        index: i32,
+        type_name: &str,
+        values: impl ArrayParam<'a, T>,
+    ) -> Result<Self, Error> {
+        self.statement = self.statement.set_array(index, type_name, values)?;
+        Ok(self)
+    }
+
+    #[cfg(feature = "serde_json")]
+    pub fn set_json_named<'a, T: serde::Serialize + ?Sized + 'a>(
+        mut self,
+        name: &str,
+        value: impl Into<Option<&'a T>>,
+    ) -> Result<Self, Error> {
+        let value = value.into();
+        for index in self.sql.indices(name)? {
+            self.statement = self.statement.set_json(index, value)?;
+        }
+        Ok(self)
+    }
+
+    #[cfg(feature = "serde_json")]
+    pub fn set_json<'a, T: serde::Serialize + ?Sized + 'a>(
+        mut self,
+        index: i32,
+        value: impl Into<Option<&'a T>>,
+    ) -> Result<Self, Error> {
+        self.statement = self.statement.set_json(index, value)?;
+        Ok(self)
+    }
+
+    pub fn set_string_named<'a>(self, name: &str, value: impl StrParam<'a>) -> Result<Self, Error> {
+        self.set_str_named(name, value.into_str(), PreparedStatement::set_string)
+    }
+
+    pub fn set_clob_named<'a>(self, name: &str, value: impl StrParam<'a>) -> Result<Self, Error> {
+        self.set_str_named(name, value.into_str(), PreparedStatement::set_clob)
+    }
+
+    pub fn set_nstring_named<'a>(
+        self,
+        name: &str,
+        value: impl StrParam<'a>,
+    ) -> Result<Self, Error> {
+        self.set_str_named(name, value.into_str(), PreparedStatement::set_nstring)
+    }
+
+    fn set_str_named<'a>(
+        mut self,
+        name: &str,
+        value: Option<&'a str>,
+        setter: fn(
+            PreparedStatement<'local>,
+            i32,
+            Option<&'a str>,
+        ) -> Result<PreparedStatement<'local>, Error>,
+    ) -> Result<Self, Error> {
+        for index in self.sql.indices(name)? {
+            self.statement = setter(self.statement, index, value)?;
+        }
+        Ok(self)
+    }
+
+    named! {
+        set_char_named => set_char(char);
+        set_byte_named => set_byte(i8);
+        set_short_named => set_short(i16);
+        set_int_named => set_int(i32);
+        set_long_named => set_long(i64);
+        set_float_named => set_float(f32);
+        set_double_named => set_double(f64);
+        set_boolean_named => set_boolean(bool);
+        set_bytes_named<'a> => set_bytes(&'a [u8]);
+        set_blob_named<'a> => set_blob(&'a [u8]);
+        #[cfg(feature = "rust_decimal")]
+        set_decimal_named => set_decimal(rust_decimal::Decimal);
+        #[cfg(feature = "time")]
+        set_time_date_named => set_time_date(time::Date);
+        #[cfg(feature = "time")]
+        set_time_time_named => set_time_time(time::Time);
+        #[cfg(feature = "time")]
+        set_time_primitive_date_time_named => set_time_primitive_date_time(time::PrimitiveDateTime);
+        #[cfg(feature = "time")]
+        set_time_offset_date_time_named => set_time_offset_date_time(time::OffsetDateTime);
+        #[cfg(feature = "uuid")]
+        set_uuid_named => set_uuid(uuid::Uuid);
+        #[cfg(feature = "chrono")]
+        set_naive_date_named => set_naive_date(chrono::NaiveDate);
+        #[cfg(feature = "chrono")]
+        set_naive_time_named => set_naive_time(chrono::NaiveTime);
+        #[cfg(feature = "chrono")]
+        set_naive_date_time_named => set_naive_date_time(chrono::NaiveDateTime);
+        #[cfg(feature = "chrono")]
+        set_offset_date_time_named => set_offset_date_time(chrono::DateTime<chrono::FixedOffset>);
+    }
+
+    delegate! {
+        set_null(index: i32, sql_type: SqlType);
+        set_string<'a>(index: i32, value: impl StrParam<'a>);
+        set_char(index: i32, value: impl Into<Option<char>>);
+        set_byte(index: i32, value: impl Into<Option<i8>>);
+        set_short(index: i32, value: impl Into<Option<i16>>);
+        set_int(index: i32, value: impl Into<Option<i32>>);
+        set_long(index: i32, value: impl Into<Option<i64>>);
+        set_float(index: i32, value: impl Into<Option<f32>>);
+        set_double(index: i32, value: impl Into<Option<f64>>);
+        set_boolean(index: i32, value: impl Into<Option<bool>>);
+        set_bytes<'a>(index: i32, value: impl Into<Option<&'a [u8]>>);
+        set_blob<'a>(index: i32, value: impl Into<Option<&'a [u8]>>);
+        set_clob<'a>(index: i32, value: impl StrParam<'a>);
+        set_nstring<'a>(index: i32, value: impl StrParam<'a>);
+        set_binary_stream(index: i32, reader: impl Read + Send + 'local, length: i64);
+        set_ascii_stream(index: i32, reader: impl Read + Send + 'local, length: i64);
+        set_character_stream(index: i32, reader: impl Read + Send + 'local);
+        #[cfg(feature = "rust_decimal")]
+        set_decimal(index: i32, value: impl Into<Option<rust_decimal::Decimal>>);
+        #[cfg(feature = "time")]
+        set_time_date(index: i32, value: impl Into<Option<time::Date>>);
+        #[cfg(feature = "time")]
+        set_time_time(index: i32, value: impl Into<Option<time::Time>>);
+        #[cfg(feature = "time")]
+        set_time_primitive_date_time(index: i32, value: impl Into<Option<time::PrimitiveDateTime>>);
+        #[cfg(feature = "time")]
+        set_time_offset_date_time(index: i32, value: impl Into<Option<time::OffsetDateTime>>);
+        #[cfg(feature = "uuid")]
+        set_uuid(index: i32, value: impl Into<Option<uuid::Uuid>>);
+        #[cfg(feature = "chrono")]
+        set_naive_date(index: i32, value: impl Into<Option<chrono::NaiveDate>>);
+        #[cfg(feature = "chrono")]
+        set_naive_time(index: i32, value: impl Into<Option<chrono::NaiveTime>>);
+        #[cfg(feature = "chrono")]
+        set_naive_date_time(index: i32, value: impl Into<Option<chrono::NaiveDateTime>>);
+        #[cfg(feature = "chrono")]
+        set_offset_date_time(index: i32, value: impl Into<Option<chrono::DateTime<chrono::FixedOffset>>>);
+        add_batch();
+        clear_batch();
+        set_query_timeout(timeout: Duration);
+        set_fetch_size(rows: i32);
+        set_max_rows(max: i32);
+        set_large_max_rows(max: i64);
+        set_fetch_direction(direction: FetchDirection);
+    }
+}
+
+impl<'local> Deref for NamedStatement<'local> {
+    type Target = PreparedStatement<'local>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.statement
+    }
+}
+
+impl<'local> DerefMut for NamedStatement<'local> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.statement
+    }
+}
@@ -0,0 +1,107 @@
+use crate::errors::Error;
+
+use super::{FromSql, PreparedStatement, ResultSet, ToSql};
+
+/// Parameters of the one-shot [`Connection`](super::Connection) queries.
+///
+/// Implemented for `()`, tuples of [`ToSql`] values, `&[&dyn ToSql]` and arrays of
+/// `&dyn ToSql`, see also [`params!`](crate::params).
+pub trait Params {
+    fn bind<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+    ) -> Result<PreparedStatement<'local>, Error>;
+}
+
+/// A row read by [`Connection::query_one`](super::Connection::query_one), implemented
+/// for tuples of [`FromSql`] values in column order.
+pub trait FromRow: Sized {
+    fn from_row(result: &ResultSet<'_>) -> Result<Self, Error>;
+}
+
+/// Builds `&[&dyn ToSql]` parameters of mixed types, e.g. `params![id, "Tom"]`.
+#[macro_export]
+macro_rules! params {
+    () => {
+        &[] as &[&dyn $crate::ToSql]
+    };
+    ($($param:expr),+ $(,)?) => {
+        &[$(&$param as &dyn $crate::ToSql),+] as &[&dyn $crate::ToSql]
+    };
+}
+
+impl Params for () {
+    fn bind<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        Ok(statement)
+    }
+}
+
+impl Params for [&dyn ToSql] {
+    fn bind<'local>(
+        &self,
+        mut statement: PreparedStatement<'local>,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        for (i, param) in self.iter().enumerate() {
+            statement = param.to_sql(statement, i as i32 + 1)?;
+        }
+        Ok(statement)
+    }
+}
+
+impl<const N: usize> Params for [&dyn ToSql; N] {
+    fn bind<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        self[..].bind(statement)
+    }
+}
+
+impl<P: Params + ?Sized> Params for &P {
+    fn bind<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        (**self).bind(statement)
+    }
+}
+
+macro_rules! tuple {
+    ($(($($T:ident $index:tt),+);)*) => {
+        $(
+            impl<$($T: ToSql),+> Params for ($($T,)+) {
+                fn bind<'local>(
+                    &self,
+                    mut statement: PreparedStatement<'local>,
+                ) -> Result<PreparedStatement<'local>, Error> {
+                    $(statement = self.$index.to_sql(statement, $index + 1)?;)+
+                    Ok(statement)
+                }
+            }
+
+            impl<$($T: FromSql),+> FromRow for ($($T,)+) {
+                fn from_row(result: &ResultSet<'_>) -> Result<Self, Error> {
+                    Ok(($(result.get::<$T, _>($index + 1)?,)+))
+                }
+            }
+        )*
+    };
+}
+
+tuple! {
+    (A 0);
+    (A 0, B 1);
+    (A 0, B 1, C 2);
+    (A 0, B 1, C 2, D 3);
+    (A 0, B 1, C 2, D 3, E 4);
+    (A 0, B 1, C 2, D 3, E 4, F 5);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+}
@@ -1,3 +1,5 @@
+use std::ops::Deref;
+
 use jni::{
     objects::{AutoLocal, JMethodID, JObject, JValueGen},
     signature::{Primitive, ReturnType},
@@ -7,7 +9,10 @@ use jni::{
 
 use crate::{errors::Error, util, Connection};
 
-use super::ResultSetMetaData;
+use super::{
+    array::{self, ArrayElement},
+    BinaryStream, CharacterStream, Column, FromSql, PreparedStatement, ResultSetMetaData,
+};
 
 pub struct ResultSet<'local> {
     inner: AutoLocal<'local, JObject<'local>>,
@@ -16,6 +21,7 @@ pub struct ResultSet<'local> {
     next: JMethodID,
     was_null: JMethodID,
     get_string: (JMethodID, JMethodID),
+    get_byte: (JMethodID, JMethodID),
     get_short: (JMethodID, JMethodID),
     get_int: (JMethodID, JMethodID),
     get_long: (JMethodID, JMethodID),
@@ -23,6 +29,21 @@ pub struct ResultSet<'local> {
     get_double: (JMethodID, JMethodID),
     get_boolean: (JMethodID, JMethodID),
     get_date: (JMethodID, JMethodID),
+    get_bytes: (JMethodID, JMethodID),
+    get_blob: (JMethodID, JMethodID),
+    get_clob: (JMethodID, JMethodID),
+    get_nclob: (JMethodID, JMethodID),
+    get_nstring: (JMethodID, JMethodID),
+    get_array: (JMethodID, JMethodID),
+    get_binary_stream: (JMethodID, JMethodID),
+    get_ascii_stream: (JMethodID, JMethodID),
+    get_character_stream: (JMethodID, JMethodID),
+    #[cfg(feature = "rust_decimal")]
+    get_big_decimal: (JMethodID, JMethodID),
+    #[cfg(any(feature = "chrono", feature = "time", feature = "uuid"))]
+    get_object_as: (JMethodID, JMethodID),
+    #[cfg(feature = "uuid")]
+    get_object: (JMethodID, JMethodID),
     env: JNIEnv<'local>,
     conn: &'local Connection<'local>,
 }
@@ -50,6 +71,9 @@ impl<'local> ResultSet<'local> {
             "(Ljava/lang/String;)Ljava/lang/String;",
         )?;
 
+        let get_byte = env.get_method_id(&class, "getByte", "(I)B")?;
+        let get_byte_by_label = env.get_method_id(&class, "getByte", "(Ljava/lang/String;)B")?;
+
         let get_short = env.get_method_id(&class, "getShort", "(I)S")?;
         let get_short_by_label = env.get_method_id(&class, "getShort", "(Ljava/lang/String;)S")?;
 
@@ -74,6 +98,93 @@ impl<'local> ResultSet<'local> {
         let get_date_by_label =
             env.get_method_id(&class, "getDate", "(Ljava/lang/String;)Ljava/sql/Date;")?;
 
+        let get_bytes = env.get_method_id(&class, "getBytes", "(I)[B")?;
+        let get_bytes_by_label = env.get_method_id(&class, "getBytes", "(Ljava/lang/String;)[B")?;
+
+        let get_blob = env.get_method_id(&class, "getBlob", "(I)Ljava/sql/Blob;")?;
+        let get_blob_by_label =
+            env.get_method_id(&class, "getBlob", "(Ljava/lang/String;)Ljava/sql/Blob;")?;
+
+        let get_clob = (
+            env.get_method_id(&class, "getClob", "(I)Ljava/sql/Clob;")?,
+            env.get_method_id(&class, "getClob", "(Ljava/lang/String;)Ljava/sql/Clob;")?,
+        );
+        let get_nclob = (
+            env.get_method_id(&class, "getNClob", "(I)Ljava/sql/NClob;")?,
+            env.get_method_id(&class, "getNClob", "(Ljava/lang/String;)Ljava/sql/NClob;")?,
+        );
+        let get_nstring = (
+            env.get_method_id(&class, "getNString", "(I)Ljava/lang/String;")?,
+            env.get_method_id(
+                &class,
+                "getNString",
+                "(Ljava/lang/String;)Ljava/lang/String;",
+            )?,
+        );
+
+        let get_array = (
+            env.get_method_id(&class, "getArray", "(I)Ljava/sql/Array;")?,
+            env.get_method_id(&class, "getArray", "(Ljava/lang/String;)Ljava/sql/Array;")?,
+        );
+
+        let get_binary_stream = (
+            env.get_method_id(&class, "getBinaryStream", "(I)Ljava/io/InputStream;")?,
+            env.get_method_id(
+                &class,
+                "getBinaryStream",
+                "(Ljava/lang/String;)Ljava/io/InputStream;",
+            )?,
+        );
+        let get_ascii_stream = (
+            env.get_method_id(&class, "getAsciiStream", "(I)Ljava/io/InputStream;")?,
+            env.get_method_id(
+                &class,
+                "getAsciiStream",
+                "(Ljava/lang/String;)Ljava/io/InputStream;",
+            )?,
+        );
+        let get_character_stream = (
+            env.get_method_id(&class, "getCharacterStream", "(I)Ljava/io/Reader;")?,
+            env.get_method_id(
+                &class,
+                "getCharacterStream",
+                "(Ljava/lang/String;)Ljava/io/Reader;",
+            )?,
+        );
+
+        #[cfg(feature = "rust_decimal")]
+        let get_big_decimal = (
+            env.get_method_id(&class, "getBigDecimal", "(I)Ljava/math/BigDecimal;")?,
+            env.get_method_id(
+                &class,
+                "getBigDecimal",
+                "(Ljava/lang/String;)Ljava/math/BigDecimal;",
+            )?,
+        );
+
+        #[cfg(any(feature = "chrono", feature = "time", feature = "uuid"))]
+        let get_object_as = (
+            env.get_method_id(
+                &class,
+                "getObject",
+                "(ILjava/lang/Class;)Ljava/lang/Object;",
+            )?,
+            env.get_method_id(
+                &class,
+                "getObject",
+                "(Ljava/lang/String;Ljava/lang/Class;)Ljava/lang/Object;",
+            )?,
+        );
+        #[cfg(feature = "uuid")]
+        let get_object = (
+            env.get_method_id(&class, "getObject", "(I)Ljava/lang/Object;")?,
+            env.get_method_id(
+                &class,
+                "getObject",
+                "(Ljava/lang/String;)Ljava/lang/Object;",
+            )?,
+        );
+
         Ok(ResultSet {
             inner: statement,
             get_meta_data,
@@ -81,6 +192,7 @@ impl<'local> ResultSet<'local> {
             next,
             was_null,
             get_string: (get_string, get_string_by_label),
+            get_byte: (get_byte, get_byte_by_label),
             get_short: (get_short, get_short_by_label),
             get_int: (get_int, get_int_by_label),
             get_long: (get_long, get_long_by_label),
@@ -88,6 +200,21 @@ impl<'local> ResultSet<'local> {
             get_double: (get_double, get_double_by_label),
             get_boolean: (get_boolean, get_boolean_by_label),
             get_date: (get_date, get_date_by_label),
+            get_bytes: (get_bytes, get_bytes_by_label),
+            get_blob: (get_blob, get_blob_by_label),
+            get_clob,
+            get_nclob,
+            get_nstring,
+            get_array,
+            get_binary_stream,
+            get_ascii_stream,
+            get_character_stream,
+            #[cfg(feature = "rust_decimal")]
+            get_big_decimal,
+            #[cfg(any(feature = "chrono", feature = "time", feature = "uuid"))]
+            get_object_as,
+            #[cfg(feature = "uuid")]
+            get_object,
             env,
             conn,
         })
@@ -96,8 +223,9 @@ impl<'local> ResultSet<'local> {
     pub fn get_meta_data(&self) -> Result<ResultSetMetaData<'local>, Error> {
         let mut env = unsafe { self.conn.env() };
         let result = unsafe {
-            env.call_method_unchecked(&self.inner, self.get_meta_data, ReturnType::Object, &[])?
+            env.call_method_unchecked(&self.inner, self.get_meta_data, ReturnType::Object, &[])
         };
+        let result = util::exception::catch(&mut env, result)?;
         if let JValueGen::Object(result) = result {
             return Ok(ResultSetMetaData::from_ref(self.conn, result)?);
         }
@@ -125,6 +253,14 @@ impl<'local> ResultSet<'local> {
         Ok(value)
     }
 
+    /// Reads any [`FromSql`] value by index or label, e.g. `result.get::<i64, _>("id")?`.
+    ///
+    /// A SQL `NULL` fails with [`Error::UnexpectedNull`] unless `T` is an `Option`.
+    pub fn get<'a, T: FromSql, C: Into<Column<'a>>>(&self, column: C) -> Result<T, Error> {
+        let column = column.into();
+        T::from_sql(self, column)?.ok_or_else(|| Error::UnexpectedNull(column.to_string()))
+    }
+
     pub fn get_string(&self, index: i32) -> Result<Option<String>, Error> {
         let method = &self.get_string.0;
         self.use_index(method, index, ReturnType::Object, |env, value| {
@@ -139,6 +275,34 @@ impl<'local> ResultSet<'local> {
         })
     }
 
+    /// A single character, e.g. of a `CHAR(1)` column, read through `getString` so a
+    /// surrogate pair decodes to one `char`.
+    pub fn get_char(&self, index: i32) -> Result<Option<char>, Error> {
+        self.get_string(index)?.map(single_char).transpose()
+    }
+
+    pub fn get_char_by_label(&self, label: &str) -> Result<Option<char>, Error> {
+        self.get_string_by_label(label)?
+            .map(single_char)
+            .transpose()
+    }
+
+    pub fn get_byte(&self, index: i32) -> Result<Option<i8>, Error> {
+        let method = &self.get_byte.0;
+        let r_type = ReturnType::Primitive(Primitive::Byte);
+        self.use_index(method, index, r_type, |_: &mut JNIEnv<'_>, value| {
+            util::cast::value_cast_i8(value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_byte_by_label(&self, label: &str) -> Result<Option<i8>, Error> {
+        let method = &self.get_byte.1;
+        let r_type = ReturnType::Primitive(Primitive::Byte);
+        self.use_label(method, label, r_type, |_: &mut JNIEnv<'_>, value| {
+            util::cast::value_cast_i8(value).map_err(Error::from)
+        })
+    }
+
     pub fn get_short(&self, index: i32) -> Result<Option<i16>, Error> {
         let method = &self.get_short.0;
         let r_type = ReturnType::Primitive(Primitive::Short);
@@ -317,6 +481,412 @@ impl<'local> ResultSet<'local> {
         }
     }
 
+    pub fn get_bytes(&self, index: i32) -> Result<Option<Vec<u8>>, Error> {
+        let method = &self.get_bytes.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            util::cast::value_cast_bytes(env, value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_bytes_by_label(&self, label: &str) -> Result<Option<Vec<u8>>, Error> {
+        let method = &self.get_bytes.1;
+        self.use_label(method, label, ReturnType::Object, |env, value| {
+            util::cast::value_cast_bytes(env, value).map_err(Error::from)
+        })
+    }
+
+    /// Reads the whole `Blob`, use [`ResultSet::get_bytes`] where the driver supports it.
+    pub fn get_blob(&self, index: i32) -> Result<Option<Vec<u8>>, Error> {
+        let method = &self.get_blob.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            let blob = AutoLocal::new(value.l()?, env);
+            util::lob::blob_to_bytes(env, &blob)
+        })
+    }
+
+    pub fn get_blob_by_label(&self, label: &str) -> Result<Option<Vec<u8>>, Error> {
+        let method = &self.get_blob.1;
+        self.use_label(method, label, ReturnType::Object, |env, value| {
+            let blob = AutoLocal::new(value.l()?, env);
+            util::lob::blob_to_bytes(env, &blob)
+        })
+    }
+
+    /// Reads the whole `Clob` in chunks, for drivers that truncate or reject
+    /// [`ResultSet::get_string`] on CLOB columns.
+    pub fn get_clob(&self, index: i32) -> Result<Option<String>, Error> {
+        let method = &self.get_clob.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            let clob = AutoLocal::new(value.l()?, env);
+            util::lob::clob_to_string(env, &clob)
+        })
+    }
+
+    pub fn get_clob_by_label(&self, label: &str) -> Result<Option<String>, Error> {
+        let method = &self.get_clob.1;
+        self.use_label(method, label, ReturnType::Object, |env, value| {
+            let clob = AutoLocal::new(value.l()?, env);
+            util::lob::clob_to_string(env, &clob)
+        })
+    }
+
+    /// Reads the whole `NClob` in chunks.
+    pub fn get_nclob(&self, index: i32) -> Result<Option<String>, Error> {
+        let method = &self.get_nclob.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            let clob = AutoLocal::new(value.l()?, env);
+            util::lob::clob_to_string(env, &clob)
+        })
+    }
+
+    pub fn get_nclob_by_label(&self, label: &str) -> Result<Option<String>, Error> {
+        let method = &self.get_nclob.1;
+        self.use_label(method, label, ReturnType::Object, |env, value| {
+            let clob = AutoLocal::new(value.l()?, env);
+            util::lob::clob_to_string(env, &clob)
+        })
+    }
+
+    /// `NCHAR`, `NVARCHAR` and `LONGNVARCHAR` columns.
+    pub fn get_nstring(&self, index: i32) -> Result<Option<String>, Error> {
+        let method = &self.get_nstring.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            util::cast::value_cast_string(env, value).map_err(Error::from)
+        })
+    }
+
+    pub fn get_nstring_by_label(&self, label: &str) -> Result<Option<String>, Error> {
+        let method = &self.get_nstring.1;
+        self.use_label(method, label, ReturnType::Object, |env, value| {
+            util::cast::value_cast_string(env, value).map_err(Error::from)
+        })
+    }
+
+    /// Reads a `java.sql.Array` column, e.g. `result.get_array::<i32>(1)?`.
+    pub fn get_array<T: ArrayElement>(&self, index: i32) -> Result<Option<Vec<Option<T>>>, Error> {
+        let method = &self.get_array.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            let array = AutoLocal::new(value.l()?, env);
+            array::from_java_array(env, &array)
+        })
+    }
+
+    pub fn get_array_by_label<T: ArrayElement>(
+        &self,
+        label: &str,
+    ) -> Result<Option<Vec<Option<T>>>, Error> {
+        let method = &self.get_array.1;
+        self.use_label(method, label, ReturnType::Object, |env, value| {
+            let array = AutoLocal::new(value.l()?, env);
+            array::from_java_array(env, &array)
+        })
+    }
+
+    /// Deserializes a JSON column read as a string.
+    #[cfg(feature = "serde_json")]
+    pub fn get_json<T: serde::de::DeserializeOwned>(&self, index: i32) -> Result<Option<T>, Error> {
+        self.get_string(index)?
+            .map(|json| from_json(&json))
+            .transpose()
+    }
+
+    #[cfg(feature = "serde_json")]
+    pub fn get_json_by_label<T: serde::de::DeserializeOwned>(
+        &self,
+        label: &str,
+    ) -> Result<Option<T>, Error> {
+        self.get_string_by_label(label)?
+            .map(|json| from_json(&json))
+            .transpose()
+    }
+
+    /// Streams a binary column in chunks instead of reading it into memory.
+    pub fn get_binary_stream(&self, index: i32) -> Result<Option<BinaryStream<'_>>, Error> {
+        let method = &self.get_binary_stream.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            BinaryStream::from_ref(env, value.l()?)
+        })
+    }
+
+    pub fn get_binary_stream_by_label(
+        &self,
+        label: &str,
+    ) -> Result<Option<BinaryStream<'_>>, Error> {
+        let method = &self.get_binary_stream.1;
+        self.use_label(method, label, ReturnType::Object, |env, value| {
+            BinaryStream::from_ref(env, value.l()?)
+        })
+    }
+
+    pub fn get_ascii_stream(&self, index: i32) -> Result<Option<BinaryStream<'_>>, Error> {
+        let method = &self.get_ascii_stream.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            BinaryStream::from_ref(env, value.l()?)
+        })
+    }
+
+    pub fn get_ascii_stream_by_label(
+        &self,
+        label: &str,
+    ) -> Result<Option<BinaryStream<'_>>, Error> {
+        let method = &self.get_ascii_stream.1;
+        self.use_label(method, label, ReturnType::Object, |env, value| {
+            BinaryStream::from_ref(env, value.l()?)
+        })
+    }
+
+    /// Streams a text column as UTF-8.
+    pub fn get_character_stream(&self, index: i32) -> Result<Option<CharacterStream<'_>>, Error> {
+        let method = &self.get_character_stream.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            CharacterStream::from_ref(env, value.l()?)
+        })
+    }
+
+    pub fn get_character_stream_by_label(
+        &self,
+        label: &str,
+    ) -> Result<Option<CharacterStream<'_>>, Error> {
+        let method = &self.get_character_stream.1;
+        self.use_label(method, label, ReturnType::Object, |env, value| {
+            CharacterStream::from_ref(env, value.l()?)
+        })
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    pub fn get_decimal(&self, index: i32) -> Result<Option<rust_decimal::Decimal>, Error> {
+        let method = &self.get_big_decimal.0;
+        self.use_index(method, index, ReturnType::Object, |env, value| {
+            let value = AutoLocal::new(value.l()?, env);
+            util::decimal::from_java(env, &value)
+        })
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    pub fn get_decimal_by_label(
+        &self,
+        label: &str,
+    ) -> Result<Option<rust_decimal::Decimal>, Error> {
+        let method = &self.get_big_decimal.1;
+        self.use_label(method, label, ReturnType::Object, |env, value| {
+            let value = AutoLocal::new(value.l()?, env);
+            util::decimal::from_java(env, &value)
+        })
+    }
+
+    /// `DATE` as `java.time.LocalDate`, without the time zone shift of `java.sql.Date`.
+    #[cfg(feature = "chrono")]
+    pub fn get_naive_date(&self, index: i32) -> Result<Option<chrono::NaiveDate>, Error> {
+        self.use_object(
+            Column::Index(index),
+            "java/time/LocalDate",
+            util::temporal::chrono::from_local_date,
+        )
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn get_naive_date_by_label(&self, label: &str) -> Result<Option<chrono::NaiveDate>, Error> {
+        self.use_object(
+            Column::Label(label),
+            "java/time/LocalDate",
+            util::temporal::chrono::from_local_date,
+        )
+    }
+
+    /// `TIME` as `java.time.LocalTime`, with nanoseconds.
+    #[cfg(feature = "chrono")]
+    pub fn get_naive_time(&self, index: i32) -> Result<Option<chrono::NaiveTime>, Error> {
+        self.use_object(
+            Column::Index(index),
+            "java/time/LocalTime",
+            util::temporal::chrono::from_local_time,
+        )
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn get_naive_time_by_label(&self, label: &str) -> Result<Option<chrono::NaiveTime>, Error> {
+        self.use_object(
+            Column::Label(label),
+            "java/time/LocalTime",
+            util::temporal::chrono::from_local_time,
+        )
+    }
+
+    /// `TIMESTAMP` as `java.time.LocalDateTime`, with nanoseconds.
+    #[cfg(feature = "chrono")]
+    pub fn get_naive_date_time(&self, index: i32) -> Result<Option<chrono::NaiveDateTime>, Error> {
+        self.use_object(
+            Column::Index(index),
+            "java/time/LocalDateTime",
+            util::temporal::chrono::from_local_date_time,
+        )
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn get_naive_date_time_by_label(
+        &self,
+        label: &str,
+    ) -> Result<Option<chrono::NaiveDateTime>, Error> {
+        self.use_object(
+            Column::Label(label),
+            "java/time/LocalDateTime",
+            util::temporal::chrono::from_local_date_time,
+        )
+    }
+
+    /// `TIMESTAMP WITH TIME ZONE` as `java.time.OffsetDateTime`.
+    #[cfg(feature = "chrono")]
+    pub fn get_offset_date_time(
+        &self,
+        index: i32,
+    ) -> Result<Option<chrono::DateTime<chrono::FixedOffset>>, Error> {
+        self.use_object(
+            Column::Index(index),
+            "java/time/OffsetDateTime",
+            util::temporal::chrono::from_offset_date_time,
+        )
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn get_offset_date_time_by_label(
+        &self,
+        label: &str,
+    ) -> Result<Option<chrono::DateTime<chrono::FixedOffset>>, Error> {
+        self.use_object(
+            Column::Label(label),
+            "java/time/OffsetDateTime",
+            util::temporal::chrono::from_offset_date_time,
+        )
+    }
+
+    /// `DATE` through `java.time.LocalDate`.
+    #[cfg(feature = "time")]
+    pub fn get_time_date(&self, index: i32) -> Result<Option<time::Date>, Error> {
+        self.use_object(
+            Column::Index(index),
+            "java/time/LocalDate",
+            util::temporal::time::from_local_date,
+        )
+    }
+
+    #[cfg(feature = "time")]
+    pub fn get_time_date_by_label(&self, label: &str) -> Result<Option<time::Date>, Error> {
+        self.use_object(
+            Column::Label(label),
+            "java/time/LocalDate",
+            util::temporal::time::from_local_date,
+        )
+    }
+
+    /// `TIME` through `java.time.LocalTime`, with nanoseconds.
+    #[cfg(feature = "time")]
+    pub fn get_time_time(&self, index: i32) -> Result<Option<time::Time>, Error> {
+        self.use_object(
+            Column::Index(index),
+            "java/time/LocalTime",
+            util::temporal::time::from_local_time,
+        )
+    }
+
+    #[cfg(feature = "time")]
+    pub fn get_time_time_by_label(&self, label: &str) -> Result<Option<time::Time>, Error> {
+        self.use_object(
+            Column::Label(label),
+            "java/time/LocalTime",
+            util::temporal::time::from_local_time,
+        )
+    }
+
+    /// `TIMESTAMP` through `java.time.LocalDateTime`, with nanoseconds.
+    #[cfg(feature = "time")]
+    pub fn get_time_primitive_date_time(
+        &self,
+        index: i32,
+    ) -> Result<Option<time::PrimitiveDateTime>, Error> {
+        self.use_object(
+            Column::Index(index),
+            "java/time/LocalDateTime",
+            util::temporal::time::from_local_date_time,
+        )
+    }
+
+    #[cfg(feature = "time")]
+    pub fn get_time_primitive_date_time_by_label(
+        &self,
+        label: &str,
+    ) -> Result<Option<time::PrimitiveDateTime>, Error> {
+        self.use_object(
+            Column::Label(label),
+            "java/time/LocalDateTime",
+            util::temporal::time::from_local_date_time,
+        )
+    }
+
+    /// `TIMESTAMP WITH TIME ZONE` through `java.time.OffsetDateTime`.
+    #[cfg(feature = "time")]
+    pub fn get_time_offset_date_time(
+        &self,
+        index: i32,
+    ) -> Result<Option<time::OffsetDateTime>, Error> {
+        self.use_object(
+            Column::Index(index),
+            "java/time/OffsetDateTime",
+            util::temporal::time::from_offset_date_time,
+        )
+    }
+
+    #[cfg(feature = "time")]
+    pub fn get_time_offset_date_time_by_label(
+        &self,
+        label: &str,
+    ) -> Result<Option<time::OffsetDateTime>, Error> {
+        self.use_object(
+            Column::Label(label),
+            "java/time/OffsetDateTime",
+            util::temporal::time::from_offset_date_time,
+        )
+    }
+
+    /// `java.util.UUID` where the driver maps it, otherwise a 16 byte binary or a
+    /// canonical string column.
+    #[cfg(feature = "uuid")]
+    pub fn get_uuid(&self, index: i32) -> Result<Option<uuid::Uuid>, Error> {
+        self.use_uuid(Column::Index(index))
+    }
+
+    #[cfg(feature = "uuid")]
+    pub fn get_uuid_by_label(&self, label: &str) -> Result<Option<uuid::Uuid>, Error> {
+        self.use_uuid(Column::Label(label))
+    }
+
+    #[cfg(feature = "uuid")]
+    fn use_uuid(&self, column: Column<'_>) -> Result<Option<uuid::Uuid>, Error> {
+        use crate::errors::ErrorKind;
+
+        match self.use_object(column, "java/util/UUID", util::uuid::from_java) {
+            // The driver does not convert the column to UUID, PostgreSQL reports it as
+            // 07006 and MySQL as S1009.
+            Err(Error::Sql(err))
+                if matches!(
+                    err.kind,
+                    ErrorKind::FeatureNotSupported | ErrorKind::DataError
+                ) || matches!(err.sql_state.as_deref(), Some("07006" | "S1009")) => {}
+            result => return result,
+        }
+        let from_java = |env: &mut JNIEnv<'local>, value: JValueGen<JObject<'local>>| {
+            let value = AutoLocal::new(value.l()?, env);
+            util::uuid::from_java(env, &value)
+        };
+        match column {
+            Column::Index(index) => {
+                self.use_index(&self.get_object.0, index, ReturnType::Object, from_java)
+            }
+            Column::Label(label) => {
+                self.use_label(&self.get_object.1, label, ReturnType::Object, from_java)
+            }
+        }
+    }
+
     fn use_index<'a, T, F>(
         &self,
         method: &JMethodID,
@@ -331,8 +901,8 @@ impl<'local> ResultSet<'local> {
         // read value
         let value = unsafe {
             env.call_method_unchecked(&self.inner, method, r_type, &[jvalue { i: index }])
-                .map_err(Error::from)
-        }?;
+        };
+        let value = util::exception::catch(&mut env, value)?;
         if self.was_null_inner(&mut env)? {
             return Ok(None);
         }
@@ -362,9 +932,9 @@ impl<'local> ResultSet<'local> {
                 r_type,
                 &[JValueGen::Object(&label).as_jni()],
             )
-            .map_err(Error::from)
-        }?;
+        };
         env.delete_local_ref(label)?;
+        let value = util::exception::catch(&mut env, value)?;
         let was_null = self.was_null_inner(&mut env)?;
         if was_null {
             return Ok(None);
@@ -373,6 +943,50 @@ impl<'local> ResultSet<'local> {
         let v = f(&mut env, value)?;
         return Ok(Some(v));
     }
+
+    /// `getObject(column, class)`, JDBC 4.2 drivers map `java.time` classes.
+    #[cfg(any(feature = "chrono", feature = "time", feature = "uuid"))]
+    fn use_object<T, F>(&self, column: Column<'_>, class: &str, f: F) -> Result<Option<T>, Error>
+    where
+        F: Fn(&mut JNIEnv<'local>, &JObject<'local>) -> Result<T, Error>,
+    {
+        let mut env: JNIEnv<'local> = unsafe { self.conn.env() };
+        let class = AutoLocal::new(env.find_class(class)?, &env);
+        let class = JValueGen::Object(&class).as_jni();
+        let value = match column {
+            Column::Index(index) => unsafe {
+                let args = [jvalue { i: index }, class];
+                let method = self.get_object_as.0;
+                env.call_method_unchecked(&self.inner, method, ReturnType::Object, &args)
+            },
+            Column::Label(label) => {
+                let label: AutoLocal<JObject> = AutoLocal::new(env.new_string(label)?.into(), &env);
+                let args = [JValueGen::Object(&*label).as_jni(), class];
+                let method = self.get_object_as.1;
+                unsafe { env.call_method_unchecked(&self.inner, method, ReturnType::Object, &args) }
+            }
+        };
+        let value = AutoLocal::new(util::exception::catch(&mut env, value)?.l()?, &env);
+        if value.is_null() {
+            return Ok(None);
+        }
+        f(&mut env, &value).map(Some)
+    }
+}
+
+fn single_char(value: String) -> Result<char, Error> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(Error::Conversion(
+            format!("{value:?} is not a single character").into(),
+        )),
+    }
+}
+
+#[cfg(feature = "serde_json")]
+fn from_json<T: serde::de::DeserializeOwned>(json: &str) -> Result<T, Error> {
+    serde_json::from_str(json).map_err(|err| Error::Conversion(err.into()))
 }
 
 impl<'a> Drop for ResultSet<'a> {
@@ -380,3 +994,28 @@ impl<'a> Drop for ResultSet<'a> {
         let _ = util::auto_close(&mut self.env, &self.inner);
     }
 }
+
+/// Rows of a one-shot [`Connection::query`], keeps the statement open while reading.
+pub struct Rows<'local> {
+    // Declared first, the result set must be closed before its statement.
+    result: ResultSet<'local>,
+    _statement: PreparedStatement<'local>,
+}
+
+impl<'local> Rows<'local> {
+    pub(crate) fn new(statement: PreparedStatement<'local>) -> Result<Self, Error> {
+        let result = statement.query_result()?;
+        Ok(Rows {
+            result,
+            _statement: statement,
+        })
+    }
+}
+
+impl<'local> Deref for Rows<'local> {
+    type Target = ResultSet<'local>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.result
+    }
+}
@@ -49,8 +49,9 @@ impl<'local> ResultSetMetaData<'local> {
                 self.get_column_name,
                 ReturnType::Object,
                 &[jvalue { i: column }],
-            )?
+            )
         };
+        let name = util::exception::catch(&mut env, name)?;
         if let JValueGen::Object(name) = name {
             return util::cast::obj_cast_string(&mut env, name).map_err(Error::from);
         }
@@ -0,0 +1,21 @@
+use jni::objects::{AutoLocal, JObject};
+
+use crate::Connection;
+
+/// `java.sql.Savepoint`, created by [`Connection::set_savepoint`].
+pub struct Savepoint<'local> {
+    inner: AutoLocal<'local, JObject<'local>>,
+}
+
+impl<'local> Savepoint<'local> {
+    pub fn from_ref(conn: &'local Connection<'local>, savepoint: JObject<'local>) -> Self {
+        let env = unsafe { conn.env() };
+        Savepoint {
+            inner: AutoLocal::new(savepoint, &env),
+        }
+    }
+
+    pub(crate) fn as_obj(&self) -> &JObject<'local> {
+        &self.inner
+    }
+}
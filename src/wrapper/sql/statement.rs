@@ -1,25 +1,179 @@
+use std::{io::Read, time::Duration};
+
 use jni::{
-    objects::{AutoLocal, JMethodID, JObject, JValueGen},
+    objects::{AutoLocal, JClass, JMethodID, JObject, JValueGen},
     signature::{Primitive, ReturnType},
     sys::jvalue,
     JNIEnv,
 };
 
-use crate::{errors::Error, util, Connection};
+use crate::{
+    errors::{Error, ErrorKind},
+    util, Connection,
+};
+
+use super::{
+    array::{self, ArrayElement, ArrayParam},
+    Blob, CancelHandle, Clob, FetchDirection, ReaderStream, ResultSet, SqlType, StrParam, ToSql,
+};
+
+/// `Statement.SUCCESS_NO_INFO`, a batch command succeeded but the row count is unknown.
+pub const SUCCESS_NO_INFO: i64 = -2;
+/// `Statement.EXECUTE_FAILED`, a batch command failed.
+pub const EXECUTE_FAILED: i64 = -3;
+
+/// Defaults applied to every statement created from a [`Connection`],
+/// configured with [`crate::Builder`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatementOptions {
+    pub query_timeout: Option<Duration>,
+    pub fetch_size: Option<i32>,
+    pub max_rows: Option<i64>,
+    pub fetch_direction: Option<FetchDirection>,
+}
+
+/// Setters shared by `java.sql.Statement` and its sub interfaces.
+struct OptionMethods {
+    set_query_timeout: JMethodID,
+    set_fetch_size: JMethodID,
+    set_max_rows: JMethodID,
+    set_large_max_rows: JMethodID,
+    set_fetch_direction: JMethodID,
+}
+
+impl OptionMethods {
+    fn new(env: &mut JNIEnv<'_>, class: &JClass<'_>) -> Result<Self, Error> {
+        Ok(OptionMethods {
+            set_query_timeout: env.get_method_id(class, "setQueryTimeout", "(I)V")?,
+            set_fetch_size: env.get_method_id(class, "setFetchSize", "(I)V")?,
+            set_max_rows: env.get_method_id(class, "setMaxRows", "(I)V")?,
+            set_large_max_rows: env.get_method_id(class, "setLargeMaxRows", "(J)V")?,
+            set_fetch_direction: env.get_method_id(class, "setFetchDirection", "(I)V")?,
+        })
+    }
+
+    /// Sub-second timeouts are rounded up, `Duration::ZERO` means no limit.
+    fn set_query_timeout<'a>(
+        &self,
+        env: &mut JNIEnv<'a>,
+        obj: &JObject<'a>,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        let mut seconds = timeout.as_secs();
+        if timeout.subsec_nanos() > 0 {
+            seconds += 1;
+        }
+        let seconds = i32::try_from(seconds).unwrap_or(i32::MAX);
+        util::call::void(env, obj, &self.set_query_timeout, &[jvalue { i: seconds }])
+    }
 
-use super::ResultSet;
+    fn set_fetch_size<'a>(
+        &self,
+        env: &mut JNIEnv<'a>,
+        obj: &JObject<'a>,
+        rows: i32,
+    ) -> Result<(), Error> {
+        util::call::void(env, obj, &self.set_fetch_size, &[jvalue { i: rows }])
+    }
+
+    fn set_max_rows<'a>(
+        &self,
+        env: &mut JNIEnv<'a>,
+        obj: &JObject<'a>,
+        max: i32,
+    ) -> Result<(), Error> {
+        util::call::void(env, obj, &self.set_max_rows, &[jvalue { i: max }])
+    }
+
+    fn set_large_max_rows<'a>(
+        &self,
+        env: &mut JNIEnv<'a>,
+        obj: &JObject<'a>,
+        max: i64,
+    ) -> Result<(), Error> {
+        util::call::void(env, obj, &self.set_large_max_rows, &[jvalue { j: max }])
+    }
+
+    fn set_fetch_direction<'a>(
+        &self,
+        env: &mut JNIEnv<'a>,
+        obj: &JObject<'a>,
+        direction: FetchDirection,
+    ) -> Result<(), Error> {
+        let direction = jvalue {
+            i: direction.into(),
+        };
+        util::call::void(env, obj, &self.set_fetch_direction, &[direction])
+    }
+
+    fn apply<'a>(
+        &self,
+        env: &mut JNIEnv<'a>,
+        obj: &JObject<'a>,
+        options: &StatementOptions,
+    ) -> Result<(), Error> {
+        if let Some(timeout) = options.query_timeout {
+            self.set_query_timeout(env, obj, timeout)?;
+        }
+        if let Some(rows) = options.fetch_size {
+            self.set_fetch_size(env, obj, rows)?;
+        }
+        // setLargeMaxRows is optional for drivers, only use it when needed.
+        if let Some(max) = options.max_rows {
+            match i32::try_from(max) {
+                Ok(max) => self.set_max_rows(env, obj, max)?,
+                Err(_) => self.set_large_max_rows(env, obj, max)?,
+            }
+        }
+        if let Some(direction) = options.fetch_direction {
+            self.set_fetch_direction(env, obj, direction)?;
+        }
+        Ok(())
+    }
+}
 
 pub struct PreparedStatement<'local> {
     inner: AutoLocal<'local, JObject<'local>>,
     execute_query: JMethodID,
     execute_update: JMethodID,
+    execute: JMethodID,
+    get_result_set: JMethodID,
+    get_update_count: JMethodID,
+    get_more_results: JMethodID,
+    get_generated_keys: JMethodID,
+    add_batch: JMethodID,
+    clear_batch: JMethodID,
+    execute_batch: JMethodID,
+    execute_large_batch: JMethodID,
     set_string: JMethodID,
+    set_byte: JMethodID,
     set_short: JMethodID,
     set_int: JMethodID,
     set_long: JMethodID,
     set_float: JMethodID,
     set_double: JMethodID,
     set_bool: JMethodID,
+    set_null: JMethodID,
+    set_bytes: JMethodID,
+    set_blob: JMethodID,
+    set_clob: JMethodID,
+    set_blob_value: JMethodID,
+    set_clob_value: JMethodID,
+    set_nstring: JMethodID,
+    set_array: JMethodID,
+    set_binary_stream: JMethodID,
+    set_ascii_stream: JMethodID,
+    set_character_stream: JMethodID,
+    /// Readers bound with `set_*_stream`, freed after the statement is closed.
+    streams: Vec<ReaderStream<'local>>,
+    #[cfg(feature = "rust_decimal")]
+    set_big_decimal: JMethodID,
+    #[cfg(any(feature = "chrono", feature = "time", feature = "uuid"))]
+    set_object: JMethodID,
+    /// `setObject(int, Object, int)`
+    #[cfg(feature = "serde_json")]
+    set_object_typed: JMethodID,
+    options: OptionMethods,
     env: JNIEnv<'local>,
     conn: &'local Connection<'local>,
 }
@@ -36,36 +190,105 @@ impl<'local> PreparedStatement<'local> {
 
         let execute_query = env.get_method_id(&class, "executeQuery", "()Ljava/sql/ResultSet;")?;
         let execute_update = env.get_method_id(&class, "executeUpdate", "()I")?;
+        let execute = env.get_method_id(&class, "execute", "()Z")?;
+        let get_result_set = env.get_method_id(&class, "getResultSet", "()Ljava/sql/ResultSet;")?;
+        let get_update_count = env.get_method_id(&class, "getUpdateCount", "()I")?;
+        let get_more_results = env.get_method_id(&class, "getMoreResults", "()Z")?;
+        let get_generated_keys =
+            env.get_method_id(&class, "getGeneratedKeys", "()Ljava/sql/ResultSet;")?;
+        let add_batch = env.get_method_id(&class, "addBatch", "()V")?;
+        let clear_batch = env.get_method_id(&class, "clearBatch", "()V")?;
+        let execute_batch = env.get_method_id(&class, "executeBatch", "()[I")?;
+        let execute_large_batch = env.get_method_id(&class, "executeLargeBatch", "()[J")?;
 
         let set_string = env.get_method_id(&class, "setString", "(ILjava/lang/String;)V")?;
+        let set_byte = env.get_method_id(&class, "setByte", "(IB)V")?;
         let set_short = env.get_method_id(&class, "setShort", "(IS)V")?;
         let set_int = env.get_method_id(&class, "setInt", "(II)V")?;
         let set_long = env.get_method_id(&class, "setLong", "(IJ)V")?;
         let set_float = env.get_method_id(&class, "setFloat", "(IF)V")?;
         let set_double = env.get_method_id(&class, "setDouble", "(ID)V")?;
         let set_bool = env.get_method_id(&class, "setBoolean", "(IZ)V")?;
+        let set_null = env.get_method_id(&class, "setNull", "(II)V")?;
+        let set_bytes = env.get_method_id(&class, "setBytes", "(I[B)V")?;
+        let set_blob = env.get_method_id(&class, "setBlob", "(ILjava/io/InputStream;J)V")?;
+        let set_clob = env.get_method_id(&class, "setClob", "(ILjava/io/Reader;J)V")?;
+        let set_blob_value = env.get_method_id(&class, "setBlob", "(ILjava/sql/Blob;)V")?;
+        let set_clob_value = env.get_method_id(&class, "setClob", "(ILjava/sql/Clob;)V")?;
+        let set_nstring = env.get_method_id(&class, "setNString", "(ILjava/lang/String;)V")?;
+        let set_array = env.get_method_id(&class, "setArray", "(ILjava/sql/Array;)V")?;
+        let set_binary_stream =
+            env.get_method_id(&class, "setBinaryStream", "(ILjava/io/InputStream;J)V")?;
+        let set_ascii_stream =
+            env.get_method_id(&class, "setAsciiStream", "(ILjava/io/InputStream;J)V")?;
+        let set_character_stream =
+            env.get_method_id(&class, "setCharacterStream", "(ILjava/io/Reader;)V")?;
+        #[cfg(feature = "rust_decimal")]
+        let set_big_decimal =
+            env.get_method_id(&class, "setBigDecimal", "(ILjava/math/BigDecimal;)V")?;
+        #[cfg(any(feature = "chrono", feature = "time", feature = "uuid"))]
+        let set_object = env.get_method_id(&class, "setObject", "(ILjava/lang/Object;)V")?;
+        #[cfg(feature = "serde_json")]
+        let set_object_typed = env.get_method_id(&class, "setObject", "(ILjava/lang/Object;I)V")?;
+        let options = OptionMethods::new(&mut env, &class)?;
 
         Ok(PreparedStatement {
             inner: statement,
             execute_query,
             execute_update,
+            execute,
+            get_result_set,
+            get_update_count,
+            get_more_results,
+            get_generated_keys,
+            add_batch,
+            clear_batch,
+            execute_batch,
+            execute_large_batch,
             set_string,
+            set_byte,
             set_short,
             set_int,
             set_long,
             set_float,
             set_double,
             set_bool,
+            set_null,
+            set_bytes,
+            set_blob,
+            set_clob,
+            set_blob_value,
+            set_clob_value,
+            set_nstring,
+            set_array,
+            set_binary_stream,
+            set_ascii_stream,
+            set_character_stream,
+            streams: Vec::new(),
+            #[cfg(feature = "rust_decimal")]
+            set_big_decimal,
+            #[cfg(any(feature = "chrono", feature = "time", feature = "uuid"))]
+            set_object,
+            #[cfg(feature = "serde_json")]
+            set_object_typed,
+            options,
             env,
             conn,
         })
     }
 
     pub fn execute_query(&self) -> Result<ResultSet, Error> {
+        self.query_result()
+    }
+
+    /// `executeQuery`, the result set is only tied to the connection, the caller
+    /// must keep the statement open while reading.
+    pub(crate) fn query_result(&self) -> Result<ResultSet<'local>, Error> {
         let mut env = unsafe { self.conn.env() };
         let result = unsafe {
-            env.call_method_unchecked(&self.inner, self.execute_query, ReturnType::Object, &[])?
+            env.call_method_unchecked(&self.inner, self.execute_query, ReturnType::Object, &[])
         };
+        let result = util::exception::catch(&mut env, result)?;
         if let JValueGen::Object(result) = result {
             return Ok(ResultSet::from_ref(self.conn, result)?);
         }
@@ -79,8 +302,9 @@ impl<'local> PreparedStatement<'local> {
                 self.execute_update,
                 ReturnType::Primitive(Primitive::Int),
                 &[],
-            )?
+            )
         };
+        let result = util::exception::catch(&mut self.env, result)?;
 
         if let JValueGen::Int(result) = result {
             return Ok(result);
@@ -88,49 +312,627 @@ impl<'local> PreparedStatement<'local> {
         return Err(Error::ImpossibleError);
     }
 
-    pub fn set_string(mut self, index: i32, value: &str) -> Result<Self, Error> {
+    /// Executes any kind of statement, returns `true` if the first result is a `ResultSet`.
+    pub fn execute(&mut self) -> Result<bool, Error> {
+        util::call::get_bool(&mut self.env, &self.inner, &self.execute)
+    }
+
+    /// The current result, `None` if it is an update count or there are no more results.
+    pub fn get_result_set(&self) -> Result<Option<ResultSet<'_>>, Error> {
+        let mut env = unsafe { self.conn.env() };
+        let result = unsafe {
+            env.call_method_unchecked(&self.inner, self.get_result_set, ReturnType::Object, &[])
+        };
+        let result = util::exception::catch(&mut env, result)?;
+        if let JValueGen::Object(result) = result {
+            if result.is_null() {
+                return Ok(None);
+            }
+            return ResultSet::from_ref(self.conn, result).map(Some);
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    /// The current result, `None` if it is a `ResultSet` or there are no more results.
+    pub fn get_update_count(&self) -> Result<Option<i64>, Error> {
+        let mut env = unsafe { self.conn.env() };
+        let count = util::call::get_int(&mut env, &self.inner, &self.get_update_count)?;
+        Ok(if count == -1 {
+            None
+        } else {
+            Some(count.into())
+        })
+    }
+
+    /// Moves to the next result, returns `true` if it is a `ResultSet`.
+    pub fn get_more_results(&self) -> Result<bool, Error> {
+        let mut env = unsafe { self.conn.env() };
+        util::call::get_bool(&mut env, &self.inner, &self.get_more_results)
+    }
+
+    /// Keys generated by the last execution, the statement must be prepared with
+    /// [`Connection::prepare_statement_returning_keys`] or
+    /// [`Connection::prepare_statement_with_key_columns`].
+    pub fn generated_keys(&self) -> Result<ResultSet<'_>, Error> {
+        let mut env = unsafe { self.conn.env() };
+        let result = unsafe {
+            env.call_method_unchecked(
+                &self.inner,
+                self.get_generated_keys,
+                ReturnType::Object,
+                &[],
+            )
+        };
+        let result = util::exception::catch(&mut env, result)?;
+        if let JValueGen::Object(result) = result {
+            return ResultSet::from_ref(self.conn, result);
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    /// Adds the current parameters to the batch.
+    pub fn add_batch(mut self) -> Result<Self, Error> {
+        util::call::void(&mut self.env, &self.inner, &self.add_batch, &[])?;
+        Ok(self)
+    }
+
+    pub fn clear_batch(mut self) -> Result<Self, Error> {
+        util::call::void(&mut self.env, &self.inner, &self.clear_batch, &[])?;
+        Ok(self)
+    }
+
+    /// Executes the batch, returns the update count of every command.
+    ///
+    /// A count may be [`SUCCESS_NO_INFO`] or [`EXECUTE_FAILED`]. On failure the
+    /// counts of the executed commands are in `SqlException::update_counts`.
+    pub fn execute_batch(&mut self) -> Result<Vec<i64>, Error> {
+        let result = unsafe {
+            self.env
+                .call_method_unchecked(&self.inner, self.execute_batch, ReturnType::Array, &[])
+        };
+        let result = util::exception::catch(&mut self.env, result)?;
+        if let JValueGen::Object(result) = result {
+            let counts = util::cast::obj_cast_i32_vec(&mut self.env, result)?;
+            return Ok(counts.into_iter().map(i64::from).collect());
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    /// Like [`PreparedStatement::execute_batch`] for update counts exceeding `i32::MAX`.
+    ///
+    /// Falls back to `executeBatch` when the driver does not implement `executeLargeBatch`.
+    pub fn execute_large_batch(&mut self) -> Result<Vec<i64>, Error> {
+        let result = unsafe {
+            self.env.call_method_unchecked(
+                &self.inner,
+                self.execute_large_batch,
+                ReturnType::Array,
+                &[],
+            )
+        };
+        let result = match util::exception::catch(&mut self.env, result) {
+            // The default `Statement.executeLargeBatch` throws UnsupportedOperationException
+            // before touching the batch.
+            Err(Error::Sql(err))
+                if err.kind == ErrorKind::FeatureNotSupported
+                    || err.class_name == "java.lang.UnsupportedOperationException" =>
+            {
+                return self.execute_batch();
+            }
+            result => result?,
+        };
+        if let JValueGen::Object(result) = result {
+            return Ok(util::cast::obj_cast_i64_vec(&mut self.env, result)?);
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    /// Binds any [`ToSql`] value, e.g. `statement.bind(1, &user_id)?`.
+    pub fn bind<T: ToSql + ?Sized>(self, index: i32, value: &T) -> Result<Self, Error> {
+        value.to_sql(self, index)
+    }
+
+    /// `setNull`, binds SQL `NULL` of the given type.
+    pub fn set_null(mut self, index: i32, sql_type: SqlType) -> Result<Self, Error> {
+        self.set_param(self.set_null, index, jvalue { i: sql_type.into() })?;
+        Ok(self)
+    }
+
+    /// `None` binds `NULL` as [`SqlType::VarChar`].
+    pub fn set_string<'a>(mut self, index: i32, value: impl StrParam<'a>) -> Result<Self, Error> {
+        let Some(value) = value.into_str() else {
+            return self.set_null(index, SqlType::VarChar);
+        };
         // new String(value)
         let value: JObject<'local> = self.env.new_string(value)?.into();
-        self.set_param(self.set_string, index, JValueGen::Object(&value).as_jni())?;
+        let result = self.set_param(self.set_string, index, JValueGen::Object(&value).as_jni());
         // del String
         self.env.delete_local_ref(value)?;
+        result?;
         Ok(self)
     }
-    pub fn set_short(mut self, index: i32, value: i16) -> Result<Self, Error> {
+    /// Binds the character as a one-character string, `None` binds `NULL` as [`SqlType::Char`].
+    pub fn set_char(self, index: i32, value: impl Into<Option<char>>) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::Char);
+        };
+        self.set_string(index, &*value.encode_utf8(&mut [0; 4]))
+    }
+    pub fn set_byte(mut self, index: i32, value: impl Into<Option<i8>>) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::TinyInt);
+        };
+        self.set_param(self.set_byte, index, jvalue { b: value })?;
+        Ok(self)
+    }
+    pub fn set_short(mut self, index: i32, value: impl Into<Option<i16>>) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::SmallInt);
+        };
         self.set_param(self.set_short, index, jvalue { s: value })?;
         Ok(self)
     }
-    pub fn set_int(mut self, index: i32, value: i32) -> Result<Self, Error> {
+    pub fn set_int(mut self, index: i32, value: impl Into<Option<i32>>) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::Integer);
+        };
         self.set_param(self.set_int, index, jvalue { i: value })?;
         Ok(self)
     }
-    pub fn set_long(mut self, index: i32, value: i64) -> Result<Self, Error> {
+    pub fn set_long(mut self, index: i32, value: impl Into<Option<i64>>) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::BigInt);
+        };
         self.set_param(self.set_long, index, jvalue { j: value })?;
         Ok(self)
     }
-    pub fn set_float(mut self, index: i32, value: f32) -> Result<Self, Error> {
+    pub fn set_float(mut self, index: i32, value: impl Into<Option<f32>>) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::Real);
+        };
         self.set_param(self.set_float, index, jvalue { f: value })?;
         Ok(self)
     }
-    pub fn set_double(mut self, index: i32, value: f64) -> Result<Self, Error> {
+    pub fn set_double(mut self, index: i32, value: impl Into<Option<f64>>) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::Double);
+        };
         self.set_param(self.set_double, index, jvalue { d: value })?;
         Ok(self)
     }
-    pub fn set_boolean(mut self, index: i32, value: bool) -> Result<Self, Error> {
+    pub fn set_boolean(
+        mut self,
+        index: i32,
+        value: impl Into<Option<bool>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::Boolean);
+        };
         self.set_param(self.set_bool, index, util::cast::bool_to_jvalue(value))?;
         Ok(self)
     }
 
+    /// `None` binds `NULL` as [`SqlType::VarBinary`].
+    pub fn set_bytes<'a>(
+        mut self,
+        index: i32,
+        value: impl Into<Option<&'a [u8]>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::VarBinary);
+        };
+        let value: JObject<'local> = self.env.byte_array_from_slice(value)?.into();
+        let result = self.set_param(self.set_bytes, index, JValueGen::Object(&value).as_jni());
+        self.env.delete_local_ref(value)?;
+        result?;
+        Ok(self)
+    }
+
+    /// Binds the bytes as a `Blob` stream, `None` binds `NULL` as [`SqlType::Blob`].
+    pub fn set_blob<'a>(
+        mut self,
+        index: i32,
+        value: impl Into<Option<&'a [u8]>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::Blob);
+        };
+        let stream = util::lob::input_stream(&mut self.env, value)?;
+        let args = [
+            jvalue { i: index },
+            JValueGen::Object(&stream).as_jni(),
+            jvalue {
+                j: value.len() as i64,
+            },
+        ];
+        let result = util::call::void(&mut self.env, &self.inner, &self.set_blob, &args);
+        self.env.delete_local_ref(stream)?;
+        result?;
+        Ok(self)
+    }
+
+    /// `setBlob` with a value from [`Connection::create_blob`].
+    pub(crate) fn set_blob_value(mut self, index: i32, value: &Blob<'_>) -> Result<Self, Error> {
+        let value = JValueGen::Object(value.as_obj()).as_jni();
+        self.set_param(self.set_blob_value, index, value)?;
+        Ok(self)
+    }
+
+    /// `setClob` with a value from [`Connection::create_clob`].
+    pub(crate) fn set_clob_value(mut self, index: i32, value: &Clob<'_>) -> Result<Self, Error> {
+        let value = JValueGen::Object(value.as_obj()).as_jni();
+        self.set_param(self.set_clob_value, index, value)?;
+        Ok(self)
+    }
+
+    /// Binds the text as a `Clob` stream, `None` binds `NULL` as [`SqlType::Clob`].
+    pub fn set_clob<'a>(mut self, index: i32, value: impl StrParam<'a>) -> Result<Self, Error> {
+        let Some(value) = value.into_str() else {
+            return self.set_null(index, SqlType::Clob);
+        };
+        let (reader, length) = util::lob::string_reader(&mut self.env, value)?;
+        let args = [
+            jvalue { i: index },
+            JValueGen::Object(&reader).as_jni(),
+            jvalue { j: length },
+        ];
+        let result = util::call::void(&mut self.env, &self.inner, &self.set_clob, &args);
+        self.env.delete_local_ref(reader)?;
+        result?;
+        Ok(self)
+    }
+
+    /// `setNString`, `None` binds `NULL` as [`SqlType::NVarChar`].
+    pub fn set_nstring<'a>(mut self, index: i32, value: impl StrParam<'a>) -> Result<Self, Error> {
+        let Some(value) = value.into_str() else {
+            return self.set_null(index, SqlType::NVarChar);
+        };
+        let value: JObject<'local> = self.env.new_string(value)?.into();
+        let result = self.set_param(self.set_nstring, index, JValueGen::Object(&value).as_jni());
+        self.env.delete_local_ref(value)?;
+        result?;
+        Ok(self)
+    }
+
+    /// Binds a `java.sql.Array` created with `Connection.createArrayOf`, `type_name` is the
+    /// database element type, e.g. `int4` on PostgreSQL or `INTEGER` on H2.
+    /// `None` binds `NULL` as [`SqlType::Array`].
+    pub fn set_array<'a, T: ArrayElement + 'a>(
+        mut self,
+        index: i32,
+        type_name: &str,
+        values: impl ArrayParam<'a, T>,
+    ) -> Result<Self, Error> {
+        let Some(values) = values.into_slice() else {
+            return self.set_null(index, SqlType::Array);
+        };
+        let elements = array::to_java_array(&mut self.env, values)?;
+        let array = self
+            .conn
+            .create_array_of(&mut self.env, type_name, &elements);
+        self.env.delete_local_ref(elements)?;
+        let array = array?;
+        let result = self.set_param(self.set_array, index, JValueGen::Object(&array).as_jni());
+        self.env.delete_local_ref(array)?;
+        result?;
+        Ok(self)
+    }
+
+    /// Streams `length` bytes from `reader` when the driver sends the parameter,
+    /// usually during execution, possibly from a driver thread.
+    pub fn set_binary_stream(
+        mut self,
+        index: i32,
+        reader: impl Read + Send + 'local,
+        length: i64,
+    ) -> Result<Self, Error> {
+        let method = self.set_binary_stream;
+        self.set_stream(method, index, reader, Some(length))?;
+        Ok(self)
+    }
+
+    pub fn set_ascii_stream(
+        mut self,
+        index: i32,
+        reader: impl Read + Send + 'local,
+        length: i64,
+    ) -> Result<Self, Error> {
+        let method = self.set_ascii_stream;
+        self.set_stream(method, index, reader, Some(length))?;
+        Ok(self)
+    }
+
+    /// Streams UTF-8 text from `reader`, decoded by a `java.io.InputStreamReader`.
+    pub fn set_character_stream(
+        mut self,
+        index: i32,
+        reader: impl Read + Send + 'local,
+    ) -> Result<Self, Error> {
+        let method = self.set_character_stream;
+        self.set_stream(method, index, reader, None)?;
+        Ok(self)
+    }
+
+    /// `setBigDecimal`, `None` binds `NULL` as [`SqlType::Decimal`].
+    #[cfg(feature = "rust_decimal")]
+    pub fn set_decimal(
+        mut self,
+        index: i32,
+        value: impl Into<Option<rust_decimal::Decimal>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::Decimal);
+        };
+        let value = util::decimal::to_java(&mut self.env, &value)?;
+        let result = self.set_param(
+            self.set_big_decimal,
+            index,
+            JValueGen::Object(&value).as_jni(),
+        );
+        self.env.delete_local_ref(value)?;
+        result?;
+        Ok(self)
+    }
+
+    /// Binds a `java.time.LocalDate`, `None` binds `NULL` as [`SqlType::Date`].
+    #[cfg(feature = "chrono")]
+    pub fn set_naive_date(
+        self,
+        index: i32,
+        value: impl Into<Option<chrono::NaiveDate>>,
+    ) -> Result<Self, Error> {
+        let value = value.into();
+        self.set_object(
+            index,
+            value.as_ref(),
+            SqlType::Date,
+            util::temporal::chrono::to_local_date,
+        )
+    }
+
+    /// Binds a `java.time.LocalTime`, `None` binds `NULL` as [`SqlType::Time`].
+    #[cfg(feature = "chrono")]
+    pub fn set_naive_time(
+        self,
+        index: i32,
+        value: impl Into<Option<chrono::NaiveTime>>,
+    ) -> Result<Self, Error> {
+        let value = value.into();
+        self.set_object(
+            index,
+            value.as_ref(),
+            SqlType::Time,
+            util::temporal::chrono::to_local_time,
+        )
+    }
+
+    /// Binds a `java.time.LocalDateTime`, `None` binds `NULL` as [`SqlType::Timestamp`].
+    #[cfg(feature = "chrono")]
+    pub fn set_naive_date_time(
+        self,
+        index: i32,
+        value: impl Into<Option<chrono::NaiveDateTime>>,
+    ) -> Result<Self, Error> {
+        let value = value.into();
+        self.set_object(
+            index,
+            value.as_ref(),
+            SqlType::Timestamp,
+            util::temporal::chrono::to_local_date_time,
+        )
+    }
+
+    /// Binds a `java.time.OffsetDateTime`, `None` binds `NULL` as
+    /// [`SqlType::TimestampWithTimezone`].
+    #[cfg(feature = "chrono")]
+    pub fn set_offset_date_time(
+        self,
+        index: i32,
+        value: impl Into<Option<chrono::DateTime<chrono::FixedOffset>>>,
+    ) -> Result<Self, Error> {
+        let value = value.into();
+        self.set_object(
+            index,
+            value.as_ref(),
+            SqlType::TimestampWithTimezone,
+            util::temporal::chrono::to_offset_date_time,
+        )
+    }
+
+    /// Binds a [`time::Date`] as `java.time.LocalDate`, `None` binds `NULL` as [`SqlType::Date`].
+    #[cfg(feature = "time")]
+    pub fn set_time_date(
+        self,
+        index: i32,
+        value: impl Into<Option<time::Date>>,
+    ) -> Result<Self, Error> {
+        let value = value.into();
+        let to_java = util::temporal::time::to_local_date;
+        self.set_object(index, value.as_ref(), SqlType::Date, to_java)
+    }
+
+    /// Binds a [`time::Time`] as `java.time.LocalTime`, `None` binds `NULL` as [`SqlType::Time`].
+    #[cfg(feature = "time")]
+    pub fn set_time_time(
+        self,
+        index: i32,
+        value: impl Into<Option<time::Time>>,
+    ) -> Result<Self, Error> {
+        let value = value.into();
+        let to_java = util::temporal::time::to_local_time;
+        self.set_object(index, value.as_ref(), SqlType::Time, to_java)
+    }
+
+    /// Binds a [`time::PrimitiveDateTime`] as `java.time.LocalDateTime`, `None` binds `NULL`
+    /// as [`SqlType::Timestamp`].
+    #[cfg(feature = "time")]
+    pub fn set_time_primitive_date_time(
+        self,
+        index: i32,
+        value: impl Into<Option<time::PrimitiveDateTime>>,
+    ) -> Result<Self, Error> {
+        let value = value.into();
+        let to_java = util::temporal::time::to_local_date_time;
+        self.set_object(index, value.as_ref(), SqlType::Timestamp, to_java)
+    }
+
+    /// Binds a [`time::OffsetDateTime`] as `java.time.OffsetDateTime`, `None` binds `NULL` as
+    /// [`SqlType::TimestampWithTimezone`].
+    #[cfg(feature = "time")]
+    pub fn set_time_offset_date_time(
+        self,
+        index: i32,
+        value: impl Into<Option<time::OffsetDateTime>>,
+    ) -> Result<Self, Error> {
+        let value = value.into();
+        let to_java = util::temporal::time::to_offset_date_time;
+        self.set_object(
+            index,
+            value.as_ref(),
+            SqlType::TimestampWithTimezone,
+            to_java,
+        )
+    }
+
+    /// Binds a `java.util.UUID`, `None` binds `NULL` as [`SqlType::Other`].
+    #[cfg(feature = "uuid")]
+    pub fn set_uuid(self, index: i32, value: impl Into<Option<uuid::Uuid>>) -> Result<Self, Error> {
+        let value = value.into();
+        self.set_object(index, value.as_ref(), SqlType::Other, util::uuid::to_java)
+    }
+
+    /// Binds the value serialized as JSON, `None` binds `NULL` as [`SqlType::Other`].
+    ///
+    /// PostgreSQL gets the text as [`SqlType::Other`] so it is cast to the `json`/`jsonb`
+    /// column, H2 gets UTF-8 bytes, which it parses as JSON text unlike a string, and other
+    /// databases get a string.
+    #[cfg(feature = "serde_json")]
+    pub fn set_json<'a, T: serde::Serialize + ?Sized + 'a>(
+        mut self,
+        index: i32,
+        value: impl Into<Option<&'a T>>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value.into() else {
+            return self.set_null(index, SqlType::Other);
+        };
+        let json = serde_json::to_string(value).map_err(|err| Error::Conversion(err.into()))?;
+        match self.conn.database_product_name()? {
+            "PostgreSQL" => {}
+            "H2" => return self.set_bytes(index, json.as_bytes()),
+            _ => return self.set_string(index, json.as_str()),
+        }
+        let json: JObject<'local> = self.env.new_string(json)?.into();
+        let args = [
+            jvalue { i: index },
+            JValueGen::Object(&json).as_jni(),
+            jvalue {
+                i: SqlType::Other.into(),
+            },
+        ];
+        let result = util::call::void(&mut self.env, &self.inner, &self.set_object_typed, &args);
+        self.env.delete_local_ref(json)?;
+        result?;
+        Ok(self)
+    }
+
+    /// A `Send` handle cancelling this statement from another thread.
+    pub fn cancel_handle(&self) -> Result<CancelHandle, Error> {
+        let mut env = unsafe { self.conn.env() };
+        CancelHandle::new(&mut env, &self.inner)
+    }
+
+    pub fn set_query_timeout(mut self, timeout: Duration) -> Result<Self, Error> {
+        self.options
+            .set_query_timeout(&mut self.env, &self.inner, timeout)?;
+        Ok(self)
+    }
+    pub fn set_fetch_size(mut self, rows: i32) -> Result<Self, Error> {
+        self.options
+            .set_fetch_size(&mut self.env, &self.inner, rows)?;
+        Ok(self)
+    }
+    pub fn set_max_rows(mut self, max: i32) -> Result<Self, Error> {
+        self.options.set_max_rows(&mut self.env, &self.inner, max)?;
+        Ok(self)
+    }
+    pub fn set_large_max_rows(mut self, max: i64) -> Result<Self, Error> {
+        self.options
+            .set_large_max_rows(&mut self.env, &self.inner, max)?;
+        Ok(self)
+    }
+    pub fn set_fetch_direction(mut self, direction: FetchDirection) -> Result<Self, Error> {
+        self.options
+            .set_fetch_direction(&mut self.env, &self.inner, direction)?;
+        Ok(self)
+    }
+
+    pub(crate) fn apply_options(mut self, options: &StatementOptions) -> Result<Self, Error> {
+        self.options.apply(&mut self.env, &self.inner, options)?;
+        Ok(self)
+    }
+
+    pub(crate) fn as_obj(&self) -> &JObject<'local> {
+        &self.inner
+    }
+
+    /// Binds `reader` as an `InputStream`, or as a UTF-8 `Reader` without `length`.
+    fn set_stream(
+        &mut self,
+        method: JMethodID,
+        index: i32,
+        reader: impl Read + Send + 'local,
+        length: Option<i64>,
+    ) -> Result<(), Error> {
+        let stream = ReaderStream::new(&mut self.env, reader)?;
+        match length {
+            Some(length) => {
+                let args = [
+                    jvalue { i: index },
+                    JValueGen::Object(stream.as_obj()).as_jni(),
+                    jvalue { j: length },
+                ];
+                util::call::void(&mut self.env, &self.inner, &method, &args)?;
+            }
+            None => {
+                let reader = stream.utf8_reader(&mut self.env)?;
+                let args = [jvalue { i: index }, JValueGen::Object(&reader).as_jni()];
+                util::call::void(&mut self.env, &self.inner, &method, &args)?;
+            }
+        }
+        self.streams.push(stream);
+        Ok(())
+    }
+
+    /// `setObject` with the Java value built by `to_java`, `None` binds `NULL` as `sql_type`.
+    #[cfg(any(feature = "chrono", feature = "time", feature = "uuid"))]
+    fn set_object<T: ?Sized>(
+        mut self,
+        index: i32,
+        value: Option<&T>,
+        sql_type: SqlType,
+        to_java: impl FnOnce(&mut JNIEnv<'local>, &T) -> Result<JObject<'local>, Error>,
+    ) -> Result<Self, Error> {
+        let Some(value) = value else {
+            return self.set_null(index, sql_type);
+        };
+        let value = to_java(&mut self.env, value)?;
+        let result = self.set_param(self.set_object, index, JValueGen::Object(&value).as_jni());
+        self.env.delete_local_ref(value)?;
+        result?;
+        Ok(self)
+    }
+
     #[inline(always)]
     fn set_param(&mut self, method: JMethodID, index: i32, value: jvalue) -> Result<(), Error> {
-        unsafe {
+        let result = unsafe {
             self.env.call_method_unchecked(
                 &self.inner,
                 method,
                 ReturnType::Primitive(Primitive::Void),
                 &[jvalue { i: index }, value],
-            )?;
-        }
+            )
+        };
+        util::exception::catch(&mut self.env, result)?;
         Ok(())
     }
 }
@@ -140,3 +942,241 @@ impl<'local> Drop for PreparedStatement<'local> {
         let _ = util::auto_close(&mut self.env, &self.inner);
     }
 }
+
+/// `java.sql.Statement`, created by [`Connection::create_statement`].
+///
+/// Executes SQL whose result shape is unknown ahead of time, e.g. DDL, `SHOW`
+/// commands or scripts returning several results.
+pub struct Statement<'local> {
+    inner: AutoLocal<'local, JObject<'local>>,
+    execute: JMethodID,
+    execute_query: JMethodID,
+    execute_update: JMethodID,
+    get_result_set: JMethodID,
+    get_update_count: JMethodID,
+    get_more_results: JMethodID,
+    options: OptionMethods,
+    env: JNIEnv<'local>,
+    conn: &'local Connection<'local>,
+}
+
+/// One result of [`Statement::execute_results`].
+#[allow(clippy::large_enum_variant)]
+pub enum StatementResult<'local> {
+    Rows(ResultSet<'local>),
+    UpdateCount(i64),
+}
+
+impl<'local> Statement<'local> {
+    pub fn from_ref(
+        conn: &'local Connection<'local>,
+        statement: JObject<'local>,
+    ) -> Result<Self, Error> {
+        let mut env = unsafe { conn.env() };
+
+        let statement = AutoLocal::new(statement, &env);
+        let class = AutoLocal::new(env.find_class("java/sql/Statement")?, &env);
+
+        let execute = env.get_method_id(&class, "execute", "(Ljava/lang/String;)Z")?;
+        let execute_query = env.get_method_id(
+            &class,
+            "executeQuery",
+            "(Ljava/lang/String;)Ljava/sql/ResultSet;",
+        )?;
+        let execute_update = env.get_method_id(&class, "executeUpdate", "(Ljava/lang/String;)I")?;
+        let get_result_set = env.get_method_id(&class, "getResultSet", "()Ljava/sql/ResultSet;")?;
+        let get_update_count = env.get_method_id(&class, "getUpdateCount", "()I")?;
+        let get_more_results = env.get_method_id(&class, "getMoreResults", "()Z")?;
+        let options = OptionMethods::new(&mut env, &class)?;
+
+        Ok(Statement {
+            inner: statement,
+            execute,
+            execute_query,
+            execute_update,
+            get_result_set,
+            get_update_count,
+            get_more_results,
+            options,
+            env,
+            conn,
+        })
+    }
+
+    /// A `Send` handle cancelling this statement from another thread.
+    pub fn cancel_handle(&self) -> Result<CancelHandle, Error> {
+        let mut env = unsafe { self.conn.env() };
+        CancelHandle::new(&mut env, &self.inner)
+    }
+
+    pub fn set_query_timeout(mut self, timeout: Duration) -> Result<Self, Error> {
+        self.options
+            .set_query_timeout(&mut self.env, &self.inner, timeout)?;
+        Ok(self)
+    }
+    pub fn set_fetch_size(mut self, rows: i32) -> Result<Self, Error> {
+        self.options
+            .set_fetch_size(&mut self.env, &self.inner, rows)?;
+        Ok(self)
+    }
+    pub fn set_max_rows(mut self, max: i32) -> Result<Self, Error> {
+        self.options.set_max_rows(&mut self.env, &self.inner, max)?;
+        Ok(self)
+    }
+    pub fn set_large_max_rows(mut self, max: i64) -> Result<Self, Error> {
+        self.options
+            .set_large_max_rows(&mut self.env, &self.inner, max)?;
+        Ok(self)
+    }
+    pub fn set_fetch_direction(mut self, direction: FetchDirection) -> Result<Self, Error> {
+        self.options
+            .set_fetch_direction(&mut self.env, &self.inner, direction)?;
+        Ok(self)
+    }
+
+    pub(crate) fn apply_options(mut self, options: &StatementOptions) -> Result<Self, Error> {
+        self.options.apply(&mut self.env, &self.inner, options)?;
+        Ok(self)
+    }
+
+    /// Executes any kind of SQL, returns `true` if the first result is a `ResultSet`.
+    pub fn execute(&self, sql: &str) -> Result<bool, Error> {
+        let mut env = unsafe { self.conn.env() };
+        let value = self.call_with_sql(
+            &mut env,
+            self.execute,
+            sql,
+            ReturnType::Primitive(Primitive::Boolean),
+        )?;
+        util::cast::value_cast_bool(value).map_err(Error::from)
+    }
+
+    pub fn execute_query(&self, sql: &str) -> Result<ResultSet<'_>, Error> {
+        let mut env = unsafe { self.conn.env() };
+        let value = self.call_with_sql(&mut env, self.execute_query, sql, ReturnType::Object)?;
+        if let JValueGen::Object(result) = value {
+            return ResultSet::from_ref(self.conn, result);
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    pub fn execute_update(&self, sql: &str) -> Result<i32, Error> {
+        let mut env = unsafe { self.conn.env() };
+        let value = self.call_with_sql(
+            &mut env,
+            self.execute_update,
+            sql,
+            ReturnType::Primitive(Primitive::Int),
+        )?;
+        util::cast::value_cast_i32(value).map_err(Error::from)
+    }
+
+    /// Executes the SQL and iterates over all of its results.
+    ///
+    /// A yielded `ResultSet` is closed when the iterator advances.
+    pub fn execute_results(&self, sql: &str) -> Result<StatementResults<'_, 'local>, Error> {
+        let is_result_set = self.execute(sql)?;
+        Ok(StatementResults {
+            statement: self,
+            is_result_set: Some(is_result_set),
+            done: false,
+        })
+    }
+
+    /// The current result, `None` if it is an update count or there are no more results.
+    pub fn get_result_set(&self) -> Result<Option<ResultSet<'_>>, Error> {
+        let mut env = unsafe { self.conn.env() };
+        let result = unsafe {
+            env.call_method_unchecked(&self.inner, self.get_result_set, ReturnType::Object, &[])
+        };
+        let result = util::exception::catch(&mut env, result)?;
+        if let JValueGen::Object(result) = result {
+            if result.is_null() {
+                return Ok(None);
+            }
+            return ResultSet::from_ref(self.conn, result).map(Some);
+        }
+        Err(Error::ImpossibleError)
+    }
+
+    /// The current result, `None` if it is a `ResultSet` or there are no more results.
+    pub fn get_update_count(&self) -> Result<Option<i64>, Error> {
+        let mut env = unsafe { self.conn.env() };
+        let count = util::call::get_int(&mut env, &self.inner, &self.get_update_count)?;
+        Ok(if count == -1 {
+            None
+        } else {
+            Some(count.into())
+        })
+    }
+
+    /// Moves to the next result, returns `true` if it is a `ResultSet`.
+    pub fn get_more_results(&self) -> Result<bool, Error> {
+        let mut env = unsafe { self.conn.env() };
+        util::call::get_bool(&mut env, &self.inner, &self.get_more_results)
+    }
+
+    fn call_with_sql(
+        &self,
+        env: &mut JNIEnv<'local>,
+        method: JMethodID,
+        sql: &str,
+        r_type: ReturnType,
+    ) -> Result<JValueGen<JObject<'local>>, Error> {
+        let sql: JObject<'_> = env.new_string(sql)?.into();
+        let value = unsafe {
+            env.call_method_unchecked(
+                &self.inner,
+                method,
+                r_type,
+                &[JValueGen::Object(&sql).as_jni()],
+            )
+        };
+        env.delete_local_ref(sql)?;
+        util::exception::catch(env, value)
+    }
+}
+
+impl<'local> Drop for Statement<'local> {
+    fn drop(&mut self) {
+        let _ = util::auto_close(&mut self.env, &self.inner);
+    }
+}
+
+/// Iterator returned by [`Statement::execute_results`].
+pub struct StatementResults<'stmt, 'local> {
+    statement: &'stmt Statement<'local>,
+    /// Type of the current result, `None` once it was yielded.
+    is_result_set: Option<bool>,
+    done: bool,
+}
+
+impl<'stmt, 'local> StatementResults<'stmt, 'local> {
+    fn next_result(&mut self) -> Result<Option<StatementResult<'stmt>>, Error> {
+        let is_result_set = match self.is_result_set.take() {
+            Some(is_result_set) => is_result_set,
+            None => self.statement.get_more_results()?,
+        };
+        if is_result_set {
+            let result = self.statement.get_result_set()?;
+            return Ok(result.map(StatementResult::Rows));
+        }
+        let count = self.statement.get_update_count()?;
+        Ok(count.map(StatementResult::UpdateCount))
+    }
+}
+
+impl<'stmt, 'local> Iterator for StatementResults<'stmt, 'local> {
+    type Item = Result<StatementResult<'stmt>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.next_result().transpose();
+        if !matches!(result, Some(Ok(_))) {
+            self.done = true;
+        }
+        result
+    }
+}
@@ -0,0 +1,502 @@
+use std::{
+    ffi::c_void,
+    io::{self, Read, Write},
+    panic::{self, AssertUnwindSafe},
+    sync::{Mutex, PoisonError},
+};
+
+use jni::{
+    objects::{
+        AutoLocal, GlobalRef, JByteArray, JCharArray, JClass, JMethodID, JObject, JValueGen,
+    },
+    signature::{Primitive, ReturnType},
+    sys::{jint, jlong, jvalue},
+    JNIEnv, NativeMethod,
+};
+
+use crate::{errors::Error, util};
+
+/// Size of the Java array used to copy chunks.
+const CHUNK: i32 = 8192;
+
+/// `rs.jdbc.RustInputStream`, compiled from `java/rs/jdbc/RustInputStream.java`.
+const INPUT_STREAM_CLASS: &[u8] = include_bytes!("../../../java/rs/jdbc/RustInputStream.class");
+
+/// Defined once per JVM, on the bootstrap class loader.
+static INPUT_STREAM: Mutex<Option<GlobalRef>> = Mutex::new(None);
+
+fn to_io_error(err: Error) -> io::Error {
+    io::Error::other(err.to_string())
+}
+
+/// A `java.io.InputStream` read through [`Read`], e.g. from [`ResultSet::get_binary_stream`].
+///
+/// [`ResultSet::get_binary_stream`]: super::ResultSet::get_binary_stream
+pub struct BinaryStream<'local> {
+    inner: AutoLocal<'local, JObject<'local>>,
+    buffer: Option<AutoLocal<'local, JByteArray<'local>>>,
+    read: JMethodID,
+    env: JNIEnv<'local>,
+}
+
+impl<'local> BinaryStream<'local> {
+    pub fn from_ref(env: &mut JNIEnv<'local>, stream: JObject<'local>) -> Result<Self, Error> {
+        let mut env = unsafe { env.unsafe_clone() };
+        let stream = AutoLocal::new(stream, &env);
+        let class = AutoLocal::new(env.find_class("java/io/InputStream")?, &env);
+        let read = env.get_method_id(&class, "read", "([BII)I")?;
+        Ok(BinaryStream {
+            inner: stream,
+            buffer: None,
+            read,
+            env,
+        })
+    }
+
+    fn read_chunk(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        if self.buffer.is_none() {
+            let buffer = self.env.new_byte_array(CHUNK)?;
+            self.buffer = Some(AutoLocal::new(buffer, &self.env));
+        }
+        let buffer: &JByteArray<'local> = self.buffer.as_ref().ok_or(Error::ImpossibleError)?;
+        let length = buf.len().min(CHUNK as usize);
+        let args = [
+            JValueGen::Object(&**buffer).as_jni(),
+            jvalue { i: 0 },
+            jvalue { i: length as i32 },
+        ];
+        let read = unsafe {
+            self.env.call_method_unchecked(
+                &self.inner,
+                self.read,
+                ReturnType::Primitive(Primitive::Int),
+                &args,
+            )
+        };
+        let read = util::exception::catch(&mut self.env, read)?.i()?;
+        if read <= 0 {
+            return Ok(0);
+        }
+        let read = read as usize;
+        let target = unsafe { &mut *(&mut buf[..read] as *mut [u8] as *mut [i8]) };
+        self.env.get_byte_array_region(buffer, 0, target)?;
+        Ok(read)
+    }
+}
+
+impl Read for BinaryStream<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        self.read_chunk(buf).map_err(to_io_error)
+    }
+}
+
+impl Drop for BinaryStream<'_> {
+    fn drop(&mut self) {
+        let _ = util::auto_close(&mut self.env, &self.inner);
+    }
+}
+
+/// A `java.io.Reader` read through [`Read`] as UTF-8, e.g. from
+/// [`ResultSet::get_character_stream`].
+///
+/// [`ResultSet::get_character_stream`]: super::ResultSet::get_character_stream
+pub struct CharacterStream<'local> {
+    inner: AutoLocal<'local, JObject<'local>>,
+    buffer: Option<AutoLocal<'local, JCharArray<'local>>>,
+    read: JMethodID,
+    /// High surrogate ending the previous chunk.
+    surrogate: Option<u16>,
+    /// UTF-8 not yet returned.
+    pending: Vec<u8>,
+    position: usize,
+    env: JNIEnv<'local>,
+}
+
+impl<'local> CharacterStream<'local> {
+    pub fn from_ref(env: &mut JNIEnv<'local>, reader: JObject<'local>) -> Result<Self, Error> {
+        let mut env = unsafe { env.unsafe_clone() };
+        let reader = AutoLocal::new(reader, &env);
+        let class = AutoLocal::new(env.find_class("java/io/Reader")?, &env);
+        let read = env.get_method_id(&class, "read", "([CII)I")?;
+        Ok(CharacterStream {
+            inner: reader,
+            buffer: None,
+            read,
+            surrogate: None,
+            pending: Vec::new(),
+            position: 0,
+            env,
+        })
+    }
+
+    /// Decodes the next chunk into `pending`, returns `false` at the end of the stream.
+    fn fill(&mut self) -> Result<bool, Error> {
+        if self.buffer.is_none() {
+            let buffer = self.env.new_char_array(CHUNK)?;
+            self.buffer = Some(AutoLocal::new(buffer, &self.env));
+        }
+        let buffer: &JCharArray<'local> = self.buffer.as_ref().ok_or(Error::ImpossibleError)?;
+        let args = [
+            JValueGen::Object(&**buffer).as_jni(),
+            jvalue { i: 0 },
+            jvalue { i: CHUNK },
+        ];
+        let read = unsafe {
+            self.env.call_method_unchecked(
+                &self.inner,
+                self.read,
+                ReturnType::Primitive(Primitive::Int),
+                &args,
+            )
+        };
+        let read = util::exception::catch(&mut self.env, read)?.i()?;
+
+        self.pending.clear();
+        self.position = 0;
+        let mut units: Vec<u16> = self.surrogate.take().into_iter().collect();
+        if read <= 0 {
+            // A dangling high surrogate decodes to U+FFFD.
+            self.pending
+                .extend(String::from_utf16_lossy(&units).as_bytes());
+            return Ok(!self.pending.is_empty());
+        }
+        let start = units.len();
+        units.resize(start + read as usize, 0);
+        self.env
+            .get_char_array_region(buffer, 0, &mut units[start..])?;
+        if let Some(&last) = units.last() {
+            if (0xD800..=0xDBFF).contains(&last) {
+                self.surrogate = units.pop();
+            }
+        }
+        self.pending
+            .extend(String::from_utf16_lossy(&units).as_bytes());
+        Ok(true)
+    }
+}
+
+impl Read for CharacterStream<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        while self.position == self.pending.len() {
+            if !self.fill().map_err(to_io_error)? {
+                return Ok(0);
+            }
+        }
+        let length = buf.len().min(self.pending.len() - self.position);
+        buf[..length].copy_from_slice(&self.pending[self.position..self.position + length]);
+        self.position += length;
+        Ok(length)
+    }
+}
+
+impl Drop for CharacterStream<'_> {
+    fn drop(&mut self) {
+        let _ = util::auto_close(&mut self.env, &self.inner);
+    }
+}
+
+/// A `java.io.OutputStream` written through [`Write`], e.g. from [`Blob::binary_writer`].
+///
+/// Closed on drop, call [`Write::flush`] first to see write errors.
+///
+/// [`Blob::binary_writer`]: super::Blob::binary_writer
+pub struct BinaryWriter<'local> {
+    inner: AutoLocal<'local, JObject<'local>>,
+    buffer: Option<AutoLocal<'local, JByteArray<'local>>>,
+    write: JMethodID,
+    flush: JMethodID,
+    env: JNIEnv<'local>,
+}
+
+impl<'local> BinaryWriter<'local> {
+    pub fn from_ref(env: &mut JNIEnv<'local>, stream: JObject<'local>) -> Result<Self, Error> {
+        let mut env = unsafe { env.unsafe_clone() };
+        let stream = AutoLocal::new(stream, &env);
+        let class = AutoLocal::new(env.find_class("java/io/OutputStream")?, &env);
+        let write = env.get_method_id(&class, "write", "([BII)V")?;
+        let flush = env.get_method_id(&class, "flush", "()V")?;
+        Ok(BinaryWriter {
+            inner: stream,
+            buffer: None,
+            write,
+            flush,
+            env,
+        })
+    }
+
+    fn write_chunk(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        if self.buffer.is_none() {
+            let buffer = self.env.new_byte_array(CHUNK)?;
+            self.buffer = Some(AutoLocal::new(buffer, &self.env));
+        }
+        let buffer: &JByteArray<'local> = self.buffer.as_ref().ok_or(Error::ImpossibleError)?;
+        let length = buf.len().min(CHUNK as usize);
+        let bytes = unsafe { &*(&buf[..length] as *const [u8] as *const [i8]) };
+        self.env.set_byte_array_region(buffer, 0, bytes)?;
+        let args = [
+            JValueGen::Object(&**buffer).as_jni(),
+            jvalue { i: 0 },
+            jvalue { i: length as i32 },
+        ];
+        util::call::void(&mut self.env, &self.inner, &self.write, &args)?;
+        Ok(length)
+    }
+}
+
+impl Write for BinaryWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        self.write_chunk(buf).map_err(to_io_error)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        util::call::void(&mut self.env, &self.inner, &self.flush, &[]).map_err(to_io_error)
+    }
+}
+
+impl Drop for BinaryWriter<'_> {
+    fn drop(&mut self) {
+        let _ = util::auto_close(&mut self.env, &self.inner);
+    }
+}
+
+/// A `java.io.Writer` written through [`Write`] as UTF-8, e.g. from
+/// [`Clob::character_writer`].
+///
+/// A UTF-8 sequence split across writes is held back until it is complete, one still
+/// incomplete on drop is written as U+FFFD. Closed on drop like [`BinaryWriter`].
+///
+/// [`Clob::character_writer`]: super::Clob::character_writer
+pub struct CharacterWriter<'local> {
+    inner: AutoLocal<'local, JObject<'local>>,
+    buffer: Option<AutoLocal<'local, JCharArray<'local>>>,
+    write: JMethodID,
+    flush: JMethodID,
+    /// Bytes not yet encoded, at most an incomplete UTF-8 sequence between writes.
+    pending: Vec<u8>,
+    units: Vec<u16>,
+    env: JNIEnv<'local>,
+}
+
+impl<'local> CharacterWriter<'local> {
+    pub fn from_ref(env: &mut JNIEnv<'local>, writer: JObject<'local>) -> Result<Self, Error> {
+        let mut env = unsafe { env.unsafe_clone() };
+        let writer = AutoLocal::new(writer, &env);
+        let class = AutoLocal::new(env.find_class("java/io/Writer")?, &env);
+        let write = env.get_method_id(&class, "write", "([CII)V")?;
+        let flush = env.get_method_id(&class, "flush", "()V")?;
+        Ok(CharacterWriter {
+            inner: writer,
+            buffer: None,
+            write,
+            flush,
+            pending: Vec::new(),
+            units: Vec::new(),
+            env,
+        })
+    }
+
+    /// Writes `units` as one chunk, a UTF-16 unit per UTF-8 byte at most keeps it in `CHUNK`.
+    fn write_units(&mut self) -> Result<(), Error> {
+        if self.units.is_empty() {
+            return Ok(());
+        }
+        if self.buffer.is_none() {
+            let buffer = self.env.new_char_array(CHUNK)?;
+            self.buffer = Some(AutoLocal::new(buffer, &self.env));
+        }
+        let buffer: &JCharArray<'local> = self.buffer.as_ref().ok_or(Error::ImpossibleError)?;
+        self.env.set_char_array_region(buffer, 0, &self.units)?;
+        let args = [
+            JValueGen::Object(&**buffer).as_jni(),
+            jvalue { i: 0 },
+            jvalue {
+                i: self.units.len() as i32,
+            },
+        ];
+        let result = util::call::void(&mut self.env, &self.inner, &self.write, &args);
+        self.units.clear();
+        result
+    }
+}
+
+impl Write for CharacterWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        // Leaves room for the up to 3 pending bytes.
+        let length = buf.len().min(CHUNK as usize - 3);
+        self.pending.extend_from_slice(&buf[..length]);
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(err) => {
+                self.pending.truncate(self.pending.len() - length);
+                return Err(io::Error::new(io::ErrorKind::InvalidData, err));
+            }
+        };
+        let text = std::str::from_utf8(&self.pending[..valid]).map_err(io::Error::other)?;
+        self.units.extend(text.encode_utf16());
+        self.pending.drain(..valid);
+        self.write_units().map_err(to_io_error)?;
+        Ok(length)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        util::call::void(&mut self.env, &self.inner, &self.flush, &[]).map_err(to_io_error)
+    }
+}
+
+impl Drop for CharacterWriter<'_> {
+    fn drop(&mut self) {
+        if !self.pending.is_empty() {
+            self.units.push(char::REPLACEMENT_CHARACTER as u16);
+            let _ = self.write_units();
+        }
+        let _ = util::auto_close(&mut self.env, &self.inner);
+    }
+}
+
+/// A Rust reader exposed to the driver as a `java.io.InputStream`.
+///
+/// Owned by the statement it is bound to, the Java side is detached before the reader
+/// is freed and fails with an `IOException` if the driver reads it afterwards.
+/// The reader is `Send` as the driver may read it from a thread of its own.
+pub(crate) struct ReaderStream<'local> {
+    source: *mut Source<'local>,
+    inner: AutoLocal<'local, JObject<'local>>,
+    env: JNIEnv<'local>,
+}
+
+impl<'local> ReaderStream<'local> {
+    pub(crate) fn new(
+        env: &mut JNIEnv<'local>,
+        reader: impl Read + Send + 'local,
+    ) -> Result<Self, Error> {
+        let class = input_stream_class(env)?;
+        let source = Box::into_raw(Box::new(Source {
+            reader: Box::new(reader),
+            chunk: vec![0; CHUNK as usize],
+        }));
+        let stream = env.new_object(
+            <&JClass>::from(class.as_obj()),
+            "(J)V",
+            &[JValueGen::Long(source as jlong)],
+        );
+        let stream = match util::exception::catch(env, stream) {
+            Ok(stream) => stream,
+            Err(err) => {
+                drop(unsafe { Box::from_raw(source) });
+                return Err(err);
+            }
+        };
+        let env = unsafe { env.unsafe_clone() };
+        Ok(ReaderStream {
+            source,
+            inner: AutoLocal::new(stream, &env),
+            env,
+        })
+    }
+
+    pub(crate) fn as_obj(&self) -> &JObject<'local> {
+        &self.inner
+    }
+
+    /// `new InputStreamReader(stream, "UTF-8")`
+    pub(crate) fn utf8_reader(
+        &self,
+        env: &mut JNIEnv<'local>,
+    ) -> Result<AutoLocal<'local, JObject<'local>>, Error> {
+        let charset = AutoLocal::new(env.new_string("UTF-8")?, env);
+        let reader = env.new_object(
+            "java/io/InputStreamReader",
+            "(Ljava/io/InputStream;Ljava/lang/String;)V",
+            &[(&*self.inner).into(), (&charset).into()],
+        );
+        let reader = util::exception::catch(env, reader)?;
+        Ok(AutoLocal::new(reader, env))
+    }
+}
+
+impl Drop for ReaderStream<'_> {
+    fn drop(&mut self) {
+        let detached = self.env.call_method(&self.inner, "detach", "()V", &[]);
+        if let Err(err) = util::exception::catch(&mut self.env, detached) {
+            // Leak the reader rather than letting the driver read freed memory.
+            log::error!("Detaching input stream failed. {}", err);
+            return;
+        }
+        drop(unsafe { Box::from_raw(self.source) });
+    }
+}
+
+/// What the `RustInputStream` handle points to, the chunk is reused across reads.
+struct Source<'local> {
+    reader: Box<dyn Read + Send + 'local>,
+    chunk: Vec<u8>,
+}
+
+fn input_stream_class(env: &mut JNIEnv<'_>) -> Result<GlobalRef, Error> {
+    let mut class = INPUT_STREAM.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(class) = class.as_ref() {
+        return Ok(class.clone());
+    }
+    let defined = env.define_class(
+        "rs/jdbc/RustInputStream",
+        &JObject::null(),
+        INPUT_STREAM_CLASS,
+    );
+    let defined = AutoLocal::new(util::exception::catch(env, defined)?, env);
+    let read = NativeMethod {
+        name: "read0".into(),
+        sig: "(J[BII)I".into(),
+        fn_ptr: read0 as *mut c_void,
+    };
+    let registered = env.register_native_methods(&defined, &[read]);
+    util::exception::catch(env, registered)?;
+    let global = env.new_global_ref(&defined)?;
+    *class = Some(global.clone());
+    Ok(global)
+}
+
+/// `RustInputStream.read0(long handle, byte[] buffer, int offset, int length)`
+extern "system" fn read0<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    handle: jlong,
+    buffer: JByteArray<'local>,
+    offset: jint,
+    length: jint,
+) -> jint {
+    let source = unsafe { &mut *(handle as *mut Source) };
+    let chunk = &mut source.chunk[..length.min(CHUNK) as usize];
+    let reader = &mut source.reader;
+    let read = panic::catch_unwind(AssertUnwindSafe(|| loop {
+        match reader.read(chunk) {
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            result => break result,
+        }
+    }));
+    let message = match read {
+        Ok(Ok(0)) => return -1,
+        Ok(Ok(read)) => {
+            let bytes = unsafe { &*(&chunk[..read] as *const [u8] as *const [i8]) };
+            // On failure an ArrayIndexOutOfBoundsException is already pending.
+            let _ = env.set_byte_array_region(&buffer, offset, bytes);
+            return read as jint;
+        }
+        Ok(Err(err)) => err.to_string(),
+        Err(_) => "Rust reader panicked".to_string(),
+    };
+    let _ = env.throw_new("java/io/IOException", message);
+    -1
+}
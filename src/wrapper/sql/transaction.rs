@@ -0,0 +1,270 @@
+use std::ops::Deref;
+
+use log::error;
+
+use crate::{
+    errors::{Error, ErrorKind},
+    Connection,
+};
+
+use super::Savepoint;
+
+/// `java.sql.Connection.TRANSACTION_*`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl IsolationLevel {
+    pub fn from_i32(level: i32) -> Option<Self> {
+        match level {
+            1 => Some(IsolationLevel::ReadUncommitted),
+            2 => Some(IsolationLevel::ReadCommitted),
+            4 => Some(IsolationLevel::RepeatableRead),
+            8 => Some(IsolationLevel::Serializable),
+            _ => None,
+        }
+    }
+}
+
+impl From<IsolationLevel> for i32 {
+    fn from(level: IsolationLevel) -> Self {
+        match level {
+            IsolationLevel::ReadUncommitted => 1,
+            IsolationLevel::ReadCommitted => 2,
+            IsolationLevel::RepeatableRead => 4,
+            IsolationLevel::Serializable => 8,
+        }
+    }
+}
+
+/// Created by [`Connection::transaction_builder`].
+///
+/// The settings only apply to the outermost transaction, they are restored when it finishes.
+/// Nested transactions keep the settings of the enclosing one.
+pub struct TransactionBuilder<'conn, 'local> {
+    conn: &'conn Connection<'local>,
+    isolation_level: Option<IsolationLevel>,
+    read_only: Option<bool>,
+}
+
+impl<'conn, 'local> TransactionBuilder<'conn, 'local> {
+    pub(crate) fn new(conn: &'conn Connection<'local>) -> Self {
+        TransactionBuilder {
+            conn,
+            isolation_level: None,
+            read_only: None,
+        }
+    }
+
+    /// Ignored by a nested transaction, which keeps the level of the enclosing one.
+    pub fn isolation_level(mut self, level: IsolationLevel) -> Self {
+        self.isolation_level = Some(level);
+        self
+    }
+
+    /// Ignored by a nested transaction, like [`TransactionBuilder::isolation_level`].
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = Some(read_only);
+        self
+    }
+
+    pub fn begin(self) -> Result<Transaction<'conn, 'local>, Error> {
+        Transaction::begin(self)
+    }
+
+    /// Runs `f` in a transaction, commits on `Ok` and rolls back on `Err` or panic.
+    pub fn in_transaction<T, E, F>(self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&Transaction<'_, 'local>) -> Result<T, E>,
+        E: From<Error>,
+    {
+        let tx = self.begin()?;
+        let value = f(&tx)?;
+        tx.commit()?;
+        Ok(value)
+    }
+}
+
+enum Scope<'conn> {
+    /// Outermost transaction, restores the connection settings when finished.
+    Root {
+        restore_auto_commit: bool,
+        /// Raw `getTransactionIsolation()`, it may be a driver specific level.
+        restore_isolation_level: Option<i32>,
+        restore_read_only: Option<bool>,
+    },
+    /// Nested transaction.
+    Savepoint(Option<Savepoint<'conn>>),
+}
+
+/// RAII transaction guard, created by [`Connection::transaction`].
+///
+/// Rolled back on drop unless [`Transaction::commit`] was called.
+pub struct Transaction<'conn, 'local> {
+    conn: &'conn Connection<'local>,
+    scope: Scope<'conn>,
+    finished: bool,
+}
+
+impl<'conn, 'local> Transaction<'conn, 'local> {
+    fn begin(builder: TransactionBuilder<'conn, 'local>) -> Result<Self, Error> {
+        let conn = builder.conn;
+        let depth = conn.transaction_depth();
+        let scope = if depth == 0 {
+            let mut scope = Scope::Root {
+                restore_auto_commit: false,
+                restore_isolation_level: None,
+                restore_read_only: None,
+            };
+            if let Err(err) = apply_settings(conn, &builder, &mut scope) {
+                // No transaction is returned, undo what was changed before the failure.
+                if let Err(err) = restore_settings(conn, &mut scope) {
+                    error!("Restoring connection settings failed. {}", err);
+                }
+                return Err(err);
+            }
+            scope
+        } else {
+            Scope::Savepoint(Some(conn.set_savepoint()?))
+        };
+        conn.set_transaction_depth(depth + 1);
+        Ok(Transaction {
+            conn,
+            scope,
+            finished: false,
+        })
+    }
+
+    /// Whether this transaction is nested in another one.
+    pub fn is_nested(&self) -> bool {
+        matches!(self.scope, Scope::Savepoint(_))
+    }
+
+    pub fn commit(mut self) -> Result<(), Error> {
+        match &mut self.scope {
+            Scope::Root { .. } => self.conn.commit()?,
+            Scope::Savepoint(savepoint) => {
+                if let Some(savepoint) = savepoint.take() {
+                    release_savepoint(self.conn, savepoint)?;
+                }
+            }
+        }
+        self.finished = true;
+        self.finish()
+    }
+
+    pub fn rollback(mut self) -> Result<(), Error> {
+        self.rollback_inner()?;
+        self.finished = true;
+        self.finish()
+    }
+
+    fn rollback_inner(&mut self) -> Result<(), Error> {
+        match &mut self.scope {
+            Scope::Root { .. } => self.conn.rollback(),
+            Scope::Savepoint(savepoint) => {
+                let Some(savepoint) = savepoint.take() else {
+                    return Ok(());
+                };
+                self.conn.rollback_to_savepoint(&savepoint)?;
+                release_savepoint(self.conn, savepoint)
+            }
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        restore_settings(self.conn, &mut self.scope)
+    }
+}
+
+/// Applies the builder settings to the connection, recording in `scope` what to restore.
+fn apply_settings(
+    conn: &Connection<'_>,
+    builder: &TransactionBuilder<'_, '_>,
+    scope: &mut Scope<'_>,
+) -> Result<(), Error> {
+    let Scope::Root {
+        restore_auto_commit,
+        restore_isolation_level,
+        restore_read_only,
+    } = scope
+    else {
+        return Ok(());
+    };
+    if let Some(read_only) = builder.read_only {
+        let previous = conn.is_read_only()?;
+        if previous != read_only {
+            conn.set_read_only(read_only)?;
+            *restore_read_only = Some(previous);
+        }
+    }
+    if let Some(level) = builder.isolation_level {
+        let previous = conn.get_transaction_isolation_raw()?;
+        if previous != i32::from(level) {
+            conn.set_transaction_isolation(level)?;
+            *restore_isolation_level = Some(previous);
+        }
+    }
+    if conn.get_auto_commit()? {
+        conn.set_auto_commit(false)?;
+        *restore_auto_commit = true;
+    }
+    Ok(())
+}
+
+/// Restores the settings recorded in a root `scope`, each only once.
+fn restore_settings(conn: &Connection<'_>, scope: &mut Scope<'_>) -> Result<(), Error> {
+    if let Scope::Root {
+        restore_auto_commit,
+        restore_isolation_level,
+        restore_read_only,
+    } = scope
+    {
+        if *restore_auto_commit {
+            *restore_auto_commit = false;
+            conn.set_auto_commit(true)?;
+        }
+        if let Some(level) = restore_isolation_level.take() {
+            conn.set_transaction_isolation_raw(level)?;
+        }
+        if let Some(read_only) = restore_read_only.take() {
+            conn.set_read_only(read_only)?;
+        }
+    }
+    Ok(())
+}
+
+fn release_savepoint(conn: &Connection<'_>, savepoint: Savepoint<'_>) -> Result<(), Error> {
+    match conn.release_savepoint(savepoint) {
+        // Releasing is optional, e.g. Oracle does not support it.
+        Err(err) if err.kind() == ErrorKind::FeatureNotSupported => Ok(()),
+        result => result,
+    }
+}
+
+impl<'conn, 'local> Deref for Transaction<'conn, 'local> {
+    type Target = Connection<'local>;
+
+    fn deref(&self) -> &Self::Target {
+        self.conn
+    }
+}
+
+impl<'conn, 'local> Drop for Transaction<'conn, 'local> {
+    fn drop(&mut self) {
+        if !self.finished {
+            if let Err(err) = self.rollback_inner() {
+                error!("Transaction rollback failed. {}", err);
+            }
+        }
+        if let Err(err) = self.finish() {
+            error!("Restoring connection settings failed. {}", err);
+        }
+        let depth = self.conn.transaction_depth();
+        self.conn.set_transaction_depth(depth.saturating_sub(1));
+    }
+}
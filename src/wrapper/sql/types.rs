@@ -0,0 +1,157 @@
+/// `java.sql.Types`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlType {
+    Bit,
+    TinyInt,
+    SmallInt,
+    Integer,
+    BigInt,
+    Float,
+    Real,
+    Double,
+    Numeric,
+    Decimal,
+    Char,
+    VarChar,
+    LongVarChar,
+    Date,
+    Time,
+    Timestamp,
+    Binary,
+    VarBinary,
+    LongVarBinary,
+    Null,
+    Other,
+    JavaObject,
+    Distinct,
+    Struct,
+    Array,
+    Blob,
+    Clob,
+    Ref,
+    DataLink,
+    Boolean,
+    RowId,
+    NChar,
+    NVarChar,
+    LongNVarChar,
+    NClob,
+    SqlXml,
+    RefCursor,
+    TimeWithTimezone,
+    TimestampWithTimezone,
+    /// Driver specific type code, e.g. `oracle.jdbc.OracleTypes.CURSOR`.
+    Vendor(i32),
+}
+
+impl From<SqlType> for i32 {
+    fn from(sql_type: SqlType) -> Self {
+        match sql_type {
+            SqlType::Bit => -7,
+            SqlType::TinyInt => -6,
+            SqlType::SmallInt => 5,
+            SqlType::Integer => 4,
+            SqlType::BigInt => -5,
+            SqlType::Float => 6,
+            SqlType::Real => 7,
+            SqlType::Double => 8,
+            SqlType::Numeric => 2,
+            SqlType::Decimal => 3,
+            SqlType::Char => 1,
+            SqlType::VarChar => 12,
+            SqlType::LongVarChar => -1,
+            SqlType::Date => 91,
+            SqlType::Time => 92,
+            SqlType::Timestamp => 93,
+            SqlType::Binary => -2,
+            SqlType::VarBinary => -3,
+            SqlType::LongVarBinary => -4,
+            SqlType::Null => 0,
+            SqlType::Other => 1111,
+            SqlType::JavaObject => 2000,
+            SqlType::Distinct => 2001,
+            SqlType::Struct => 2002,
+            SqlType::Array => 2003,
+            SqlType::Blob => 2004,
+            SqlType::Clob => 2005,
+            SqlType::Ref => 2006,
+            SqlType::DataLink => 70,
+            SqlType::Boolean => 16,
+            SqlType::RowId => -8,
+            SqlType::NChar => -15,
+            SqlType::NVarChar => -9,
+            SqlType::LongNVarChar => -16,
+            SqlType::NClob => 2011,
+            SqlType::SqlXml => 2009,
+            SqlType::RefCursor => 2012,
+            SqlType::TimeWithTimezone => 2013,
+            SqlType::TimestampWithTimezone => 2014,
+            SqlType::Vendor(code) => code,
+        }
+    }
+}
+
+impl From<i32> for SqlType {
+    fn from(code: i32) -> Self {
+        match code {
+            -7 => SqlType::Bit,
+            -6 => SqlType::TinyInt,
+            5 => SqlType::SmallInt,
+            4 => SqlType::Integer,
+            -5 => SqlType::BigInt,
+            6 => SqlType::Float,
+            7 => SqlType::Real,
+            8 => SqlType::Double,
+            2 => SqlType::Numeric,
+            3 => SqlType::Decimal,
+            1 => SqlType::Char,
+            12 => SqlType::VarChar,
+            -1 => SqlType::LongVarChar,
+            91 => SqlType::Date,
+            92 => SqlType::Time,
+            93 => SqlType::Timestamp,
+            -2 => SqlType::Binary,
+            -3 => SqlType::VarBinary,
+            -4 => SqlType::LongVarBinary,
+            0 => SqlType::Null,
+            1111 => SqlType::Other,
+            2000 => SqlType::JavaObject,
+            2001 => SqlType::Distinct,
+            2002 => SqlType::Struct,
+            2003 => SqlType::Array,
+            2004 => SqlType::Blob,
+            2005 => SqlType::Clob,
+            2006 => SqlType::Ref,
+            70 => SqlType::DataLink,
+            16 => SqlType::Boolean,
+            -8 => SqlType::RowId,
+            -15 => SqlType::NChar,
+            -9 => SqlType::NVarChar,
+            -16 => SqlType::LongNVarChar,
+            2011 => SqlType::NClob,
+            2009 => SqlType::SqlXml,
+            2012 => SqlType::RefCursor,
+            2013 => SqlType::TimeWithTimezone,
+            2014 => SqlType::TimestampWithTimezone,
+            code => SqlType::Vendor(code),
+        }
+    }
+}
+
+/// `ResultSet.FETCH_*`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FetchDirection {
+    Forward,
+    Reverse,
+    Unknown,
+}
+
+impl From<FetchDirection> for i32 {
+    fn from(direction: FetchDirection) -> Self {
+        match direction {
+            FetchDirection::Forward => 1000,
+            FetchDirection::Reverse => 1001,
+            FetchDirection::Unknown => 1002,
+        }
+    }
+}
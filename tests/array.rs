@@ -0,0 +1,56 @@
+use jdbc::errors::Error;
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_array() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute(
+        "create table post(id int, tags varchar(32) array, scores int array)",
+        (),
+    )?;
+
+    let tags = ["rust".to_string(), "jdbc".to_string(), "中文".to_string()];
+    conn.prepare_statement("insert into post(id, tags, scores) values(?, ?, ?)")?
+        .set_int(1, 1)?
+        .set_array(2, "VARCHAR", &tags)?
+        .set_array(3, "INTEGER", &[3, 1, 2])?
+        .execute_update()?;
+    conn.execute(
+        "insert into post(id, tags, scores) values(2, array[], array[1, null])",
+        (),
+    )?;
+    conn.execute("insert into post(id) values(3)", ())?;
+    conn.prepare_statement("insert into post(id, tags, scores) values(?, ?, ?)")?
+        .set_int(1, 4)?
+        .set_array::<String>(2, "VARCHAR", None)?
+        .set_array(3, "INTEGER", Some(vec![1, 2].as_slice()))?
+        .execute_update()?;
+
+    let statement = conn.prepare_statement("select tags, scores from post order by id")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    let expected: Vec<Option<String>> = tags.iter().cloned().map(Some).collect();
+    assert_eq!(result.get_array::<String>(1)?, Some(expected.clone()));
+    assert_eq!(result.get_array_by_label::<String>("tags")?, Some(expected));
+    assert_eq!(
+        result.get_array::<i64>(2)?,
+        Some(vec![Some(3), Some(1), Some(2)])
+    );
+    assert!(result.next()?);
+    assert_eq!(result.get_array::<String>(1)?, Some(vec![]));
+    assert_eq!(result.get_array::<i32>(2)?, Some(vec![Some(1), None]));
+    assert!(result.next()?);
+    assert_eq!(result.get_array::<String>(1)?, None);
+    assert!(result.next()?);
+    assert_eq!(result.get_array::<String>(1)?, None);
+    assert_eq!(result.get_array::<i32>(2)?, Some(vec![Some(1), Some(2)]));
+
+    let scores: Vec<Option<i32>> =
+        conn.query_scalar("select scores from post where id = ?", (1,))?;
+    assert_eq!(scores, vec![Some(3), Some(1), Some(2)]);
+    Ok(())
+}
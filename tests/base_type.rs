@@ -7,6 +7,14 @@ fn test() -> Result<(), jdbc::errors::Error> {
     let ds = util::sqlite();
     let conn = ds.get_connection()?;
 
+    let statement = conn
+        .prepare_statement("select ? as value")?
+        .set_byte(1, i8::MIN)?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_byte(1)?, Some(i8::MIN));
+    assert_eq!(result.get_byte_by_label("value")?, Some(i8::MIN));
+
     let statement = conn
         .prepare_statement("select ? as value")?
         .set_short(1, i16::MAX)?;
@@ -66,6 +74,8 @@ fn test_null() -> Result<(), jdbc::errors::Error> {
     let statement = conn.prepare_statement("select NULL")?;
     let result = statement.execute_query()?;
     assert_eq!(result.next()?, true);
+    assert_eq!(result.get_byte(1)?, None);
+    assert_eq!(result.get_char(1)?, None);
     assert_eq!(result.get_short(1)?, None);
     assert_eq!(result.get_int(1)?, None);
     assert_eq!(result.get_long(1)?, None);
@@ -75,3 +85,41 @@ fn test_null() -> Result<(), jdbc::errors::Error> {
     assert_eq!(result.was_null()?, true);
     Ok(())
 }
+
+#[test]
+fn test_char() -> Result<(), jdbc::errors::Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute(
+        "create table grade(id int, mark char(1), note varchar(8))",
+        (),
+    )?;
+
+    conn.prepare_statement("insert into grade(id, mark, note) values(?, ?, ?)")?
+        .set_int(1, 1)?
+        .set_char(2, 'A')?
+        .set_char(3, '😀')?
+        .add_batch()?
+        .set_int(1, 2)?
+        .set_char(2, None)?
+        .set_string(3, "AB")?
+        .add_batch()?
+        .execute_batch()?;
+
+    let statement = conn.prepare_statement("select mark, note from grade order by id")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_char(1)?, Some('A'));
+    assert_eq!(result.get_char_by_label("mark")?, Some('A'));
+    assert_eq!(result.get_char(2)?, Some('😀'));
+    assert!(result.next()?);
+    assert_eq!(result.get_char(1)?, None);
+    assert!(matches!(
+        result.get_char_by_label("note"),
+        Err(jdbc::errors::Error::Conversion(_))
+    ));
+
+    let mark: char = conn.query_scalar("select mark from grade where id = ?", (1,))?;
+    assert_eq!(mark, 'A');
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use jdbc::errors::Error;
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_batch() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id int primary key, name VARCHAR(255));")?
+        .execute_update()?;
+
+    let mut statement = conn.prepare_statement("insert into test(id,name) values(?,?);")?;
+    for i in 0..100 {
+        statement = statement
+            .set_int(1, i)?
+            .set_string(2, &format!("name{}", i))?
+            .add_batch()?;
+    }
+    let counts = statement.execute_batch()?;
+    assert_eq!(counts, vec![1; 100]);
+
+    // The batch is empty after execution.
+    assert_eq!(statement.execute_batch()?, Vec::<i64>::new());
+
+    let statement = conn.prepare_statement("select count(*) from test")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_int(1)?, Some(100));
+    Ok(())
+}
+
+#[test]
+fn test_large_batch() -> Result<(), Error> {
+    // Falls back to executeBatch where executeLargeBatch is not implemented.
+    for ds in [util::sqlite(), util::h2(), util::hsqldb()] {
+        let conn = ds.get_connection()?;
+        conn.execute("create table test(id int primary key)", ())?;
+
+        let mut statement = conn
+            .prepare_statement("insert into test(id) values(?)")?
+            .set_int(1, 1)?
+            .add_batch()?
+            .set_int(1, 2)?
+            .add_batch()?;
+        assert_eq!(statement.execute_large_batch()?, vec![1, 1]);
+        let count: i64 = conn.query_scalar("select count(*) from test", ())?;
+        assert_eq!(count, 2);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_batch_failure() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id int primary key);")?
+        .execute_update()?;
+
+    let mut statement = conn
+        .prepare_statement("insert into test(id) values(?);")?
+        .set_int(1, 1)?
+        .add_batch()?
+        .set_int(1, 1)?
+        .add_batch()?;
+    let err = statement.execute_batch().err().unwrap();
+    if let Error::Sql(err) = err {
+        assert_eq!(err.class_name, "java.sql.BatchUpdateException");
+        let counts = err.update_counts.unwrap();
+        assert_eq!(counts[0], 1);
+    } else {
+        panic!("unexpected error: {}", err);
+    }
+
+    let mut statement = conn
+        .prepare_statement("insert into test(id) values(?);")?
+        .set_int(1, 2)?
+        .add_batch()?
+        .clear_batch()?;
+    assert_eq!(statement.execute_batch()?, Vec::<i64>::new());
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use jdbc::errors::Error;
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_bytes() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.execute("create table file(id int, data blob)", ())?;
+
+    let data: Vec<u8> = (0..=255).collect();
+    conn.prepare_statement("insert into file(id, data) values(?, ?)")?
+        .set_int(1, 1)?
+        .set_bytes(2, data.as_slice())?
+        .add_batch()?
+        .set_int(1, 2)?
+        .set_bytes(2, None)?
+        .add_batch()?
+        .execute_batch()?;
+
+    let statement = conn.prepare_statement("select data from file order by id")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_bytes(1)?, Some(data.clone()));
+    assert_eq!(result.get_bytes_by_label("data")?, Some(data.clone()));
+    assert!(result.next()?);
+    assert_eq!(result.get_bytes(1)?, None);
+
+    let empty: Vec<u8> = conn.query_scalar("select ?", (Vec::<u8>::new(),))?;
+    assert!(empty.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_blob() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute("create table file(id int, data blob)", ())?;
+
+    let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+    conn.prepare_statement("insert into file(id, data) values(?, ?)")?
+        .set_int(1, 1)?
+        .set_blob(2, data.as_slice())?
+        .execute_update()?;
+    conn.prepare_statement("insert into file(id, data) values(?, ?)")?
+        .set_int(1, 2)?
+        .set_blob(2, None)?
+        .execute_update()?;
+
+    let statement = conn.prepare_statement("select data from file order by id")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_blob(1)?, Some(data.clone()));
+    assert_eq!(result.get_blob_by_label("data")?, Some(data));
+    assert!(result.next()?);
+    assert_eq!(result.get_blob(1)?, None);
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use jdbc::{errors::Error, wrapper::sql::SqlType};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_out_parameter() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement(r#"create alias my_abs for "java.lang.Math.abs(int)";"#)?
+        .execute_update()?;
+
+    let mut statement = conn
+        .prepare_call("{? = call my_abs(?)}")?
+        .register_out_parameter(1, SqlType::Integer)?
+        .set_int(2, -5)?;
+    statement.execute()?;
+    assert_eq!(statement.get_int(1)?, Some(5));
+    assert_eq!(statement.get_long(1)?, Some(5));
+    assert_eq!(statement.get_string(1)?, Some("5".into()));
+    assert!(!statement.was_null()?);
+    Ok(())
+}
+
+#[test]
+fn test_result_set() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement(r#"create alias my_abs for "java.lang.Math.abs(int)";"#)?
+        .execute_update()?;
+
+    let mut statement = conn.prepare_call("{call my_abs(?)}")?.set_int(1, -7)?;
+    assert!(statement.execute()?);
+    let result = statement.get_result_set()?.unwrap();
+    assert!(result.next()?);
+    assert_eq!(result.get_int(1)?, Some(7));
+    Ok(())
+}
+
+#[test]
+fn test_named_parameter() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement(r#"create alias my_abs for "java.lang.Math.abs(long)";"#)?
+        .execute_update()?;
+    conn.prepare_statement(
+        r#"create alias my_value_of for "java.lang.String.valueOf(java.lang.Object)";"#,
+    )?
+    .execute_update()?;
+
+    // H2 names the parameters P1, P2, ... in order of appearance.
+    let mut statement = conn
+        .prepare_call("{? = call my_abs(?)}")?
+        .register_out_parameter_by_name("P1", SqlType::BigInt)?
+        .set_long_by_name("P2", -5)?;
+    statement.execute()?;
+    assert_eq!(statement.get_long_by_name("P1")?, Some(5));
+    assert_eq!(statement.get_int_by_name("P1")?, Some(5));
+    assert!(!statement.was_null()?);
+
+    let mut statement = conn
+        .prepare_call("{? = call my_abs(?)}")?
+        .register_out_parameter_by_name("P1", SqlType::BigInt)?
+        .set_null_by_name("P2", SqlType::BigInt)?;
+    statement.execute()?;
+    assert_eq!(statement.get_long_by_name("P1")?, None);
+    assert!(statement.was_null()?);
+
+    let mut statement = conn
+        .prepare_call("{? = call my_value_of(?)}")?
+        .register_out_parameter_by_name("P1", SqlType::VarChar)?
+        .set_string_by_name("P2", "abc")?;
+    statement.execute()?;
+    assert_eq!(statement.get_string_by_name("P1")?, Some("abc".into()));
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use std::{thread, time::Duration};
+
+use jdbc::{errors::Error, wrapper::sql::CancelHandle};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+fn assert_send<T: Send + Sync>(_: &T) {}
+
+#[test]
+fn test_cancel_idle() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    let statement = conn.prepare_statement("select 1")?;
+    let handle: CancelHandle = statement.cancel_handle()?;
+    assert_send(&handle);
+    thread::spawn(move || handle.cancel())
+        .join()
+        .expect("cancel thread panicked")?;
+
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    Ok(())
+}
+
+#[test]
+fn test_cancel_running() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    let statement = conn.prepare_statement(
+        "select count(*) from system_range(1, 1000000) a, system_range(1, 1000000) b",
+    )?;
+    let handle = statement.cancel_handle()?;
+    let canceller = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(500));
+        handle.cancel()
+    });
+
+    let result = statement.execute_query();
+    canceller.join().expect("cancel thread panicked")?;
+    match result {
+        Err(Error::Sql(e)) => assert_eq!(e.sql_state.as_deref(), Some("57014")),
+        _ => panic!("statement was not cancelled"),
+    }
+    Ok(())
+}
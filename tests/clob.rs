@@ -0,0 +1,68 @@
+use jdbc::errors::Error;
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_clob() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute("create table doc(id int, body clob, note nclob)", ())?;
+
+    // Longer than one chunk, with a surrogate pair on the chunk boundary.
+    let body = format!("{}😀{}", "a".repeat(32767), "中文 😀 ".repeat(20_000));
+    conn.prepare_statement("insert into doc(id, body, note) values(?, ?, ?)")?
+        .set_int(1, 1)?
+        .set_clob(2, &body)?
+        .set_clob(3, "nclob")?
+        .execute_update()?;
+    conn.prepare_statement("insert into doc(id, body, note) values(?, ?, ?)")?
+        .set_int(1, 2)?
+        .set_clob(2, None)?
+        .set_clob(3, None)?
+        .execute_update()?;
+
+    let statement = conn.prepare_statement("select body, note from doc order by id")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_clob(1)?.as_deref(), Some(body.as_str()));
+    assert_eq!(
+        result.get_clob_by_label("body")?.as_deref(),
+        Some(body.as_str())
+    );
+    assert_eq!(result.get_nclob(2)?.as_deref(), Some("nclob"));
+    assert_eq!(result.get_nclob_by_label("note")?.as_deref(), Some("nclob"));
+    assert!(result.next()?);
+    assert_eq!(result.get_clob(1)?, None);
+    assert_eq!(result.get_nclob(2)?, None);
+    Ok(())
+}
+
+#[test]
+fn test_nstring() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute("create table person(id int, name nvarchar(32))", ())?;
+
+    conn.prepare_statement("insert into person(id, name) values(?, ?)")?
+        .set_int(1, 1)?
+        .set_nstring(2, "Zoë 中文")?
+        .add_batch()?
+        .set_int(1, 2)?
+        .set_nstring(2, None)?
+        .add_batch()?
+        .execute_batch()?;
+
+    let statement = conn.prepare_statement("select name from person order by id")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_nstring(1)?.as_deref(), Some("Zoë 中文"));
+    assert_eq!(
+        result.get_nstring_by_label("name")?.as_deref(),
+        Some("Zoë 中文")
+    );
+    assert!(result.next()?);
+    assert_eq!(result.get_nstring(1)?, None);
+    Ok(())
+}
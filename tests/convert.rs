@@ -0,0 +1,95 @@
+use jdbc::{
+    errors::Error,
+    wrapper::sql::{Column, PreparedStatement, ResultSet, SqlType},
+    FromSql, ToSql,
+};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct UserId(i64);
+
+impl ToSql for UserId {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        self.0.to_sql(statement, index)
+    }
+
+    fn sql_type() -> SqlType {
+        i64::sql_type()
+    }
+}
+
+impl FromSql for UserId {
+    fn from_sql(result: &ResultSet<'_>, column: Column<'_>) -> Result<Option<Self>, Error> {
+        Ok(i64::from_sql(result, column)?.map(UserId))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Admin,
+    Guest,
+}
+
+impl ToSql for Role {
+    fn to_sql<'local>(
+        &self,
+        statement: PreparedStatement<'local>,
+        index: i32,
+    ) -> Result<PreparedStatement<'local>, Error> {
+        let role = match self {
+            Role::Admin => "admin",
+            Role::Guest => "guest",
+        };
+        role.to_sql(statement, index)
+    }
+}
+
+impl FromSql for Role {
+    fn from_sql(result: &ResultSet<'_>, column: Column<'_>) -> Result<Option<Self>, Error> {
+        String::from_sql(result, column)?
+            .map(|role| match role.as_str() {
+                "admin" => Ok(Role::Admin),
+                "guest" => Ok(Role::Guest),
+                other => Err(Error::Conversion(format!("unknown role {other}").into())),
+            })
+            .transpose()
+    }
+}
+
+#[test]
+fn test_convert() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table user(id bigint, name text, role text, age int);")?
+        .execute_update()?;
+
+    let age: Option<i32> = None;
+    conn.prepare_statement("insert into user(id, name, role, age) values(?, ?, ?, ?)")?
+        .bind(1, &UserId(7))?
+        .bind(2, "alice")?
+        .bind(3, &Role::Admin)?
+        .bind(4, &age)?
+        .execute_update()?;
+
+    let statement = conn
+        .prepare_statement("select id, name, role, age from user where id = ?")?
+        .bind(1, &UserId(7))?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get::<UserId, _>(1)?, UserId(7));
+    assert_eq!(result.get::<String, _>("name")?, "alice");
+    assert_eq!(result.get::<Role, _>("role")?, Role::Admin);
+    assert_eq!(result.get::<Option<i32>, _>("age")?, None);
+    assert!(matches!(
+        result.get::<i32, _>("age"),
+        Err(Error::UnexpectedNull(column)) if column == "age"
+    ));
+    Ok(())
+}
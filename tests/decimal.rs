@@ -0,0 +1,91 @@
+#![cfg(feature = "rust_decimal")]
+
+use std::str::FromStr;
+
+use jdbc::{errors::Error, util::decimal::to_decimal};
+use rust_decimal::Decimal;
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+fn dec(value: &str) -> Decimal {
+    Decimal::from_str(value).unwrap()
+}
+
+#[test]
+fn test_to_decimal() -> Result<(), Error> {
+    // 12345 and -12345 as BigInteger.toByteArray()
+    assert_eq!(to_decimal(&[0x30, 0x39], 2)?, dec("123.45"));
+    assert_eq!(to_decimal(&[0xcf, 0xc7], 2)?, dec("-123.45"));
+    assert_eq!(to_decimal(&[0x05], -3)?, dec("5000"));
+    assert_eq!(to_decimal(&[0x00], 0)?, Decimal::ZERO);
+
+    // 15 and 25 with 29 decimal places round half to even.
+    assert_eq!(
+        to_decimal(&[0x0f], 29)?,
+        dec("0.0000000000000000000000000002")
+    );
+    assert_eq!(
+        to_decimal(&[0x19], 29)?,
+        dec("0.0000000000000000000000000002")
+    );
+
+    // 2^96 does not fit.
+    let mut too_big = vec![0x01];
+    too_big.extend([0; 12]);
+    assert!(matches!(to_decimal(&too_big, 0), Err(Error::Conversion(_))));
+    assert!(matches!(
+        to_decimal(&[0x01; 17], 2),
+        Err(Error::Conversion(_))
+    ));
+    assert!(matches!(
+        to_decimal(&[0x01], -40),
+        Err(Error::Conversion(_))
+    ));
+
+    // Exponents far out of range return without scaling digit by digit.
+    assert_eq!(to_decimal(&[0x00], i32::MIN)?, Decimal::ZERO);
+    assert_eq!(to_decimal(&[], i32::MAX)?, Decimal::ZERO);
+    assert_eq!(
+        to_decimal(&[0x01], -28)?,
+        dec("10000000000000000000000000000")
+    );
+    assert!(matches!(
+        to_decimal(&[0x01], i32::MIN),
+        Err(Error::Conversion(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_decimal() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute("create table money(id int, amount decimal(30, 4))", ())?;
+
+    let amount = dec("12345678901234567890.1234");
+    conn.prepare_statement("insert into money(id, amount) values(?, ?)")?
+        .set_int(1, 1)?
+        .set_decimal(2, amount)?
+        .add_batch()?
+        .set_int(1, 2)?
+        .set_decimal(2, None)?
+        .add_batch()?
+        .execute_batch()?;
+
+    let statement = conn.prepare_statement("select amount from money order by id")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_decimal(1)?, Some(amount));
+    assert_eq!(result.get_decimal_by_label("amount")?, Some(amount));
+    assert!(result.next()?);
+    assert_eq!(result.get_decimal(1)?, None);
+
+    let total: Decimal = conn.query_scalar("select sum(amount) from money", ())?;
+    assert_eq!(total, amount);
+
+    let result = conn.query_scalar::<Decimal, _>("select cast(1e40 as decimal(41, 0))", ());
+    assert!(matches!(result, Err(Error::Conversion(_))));
+    Ok(())
+}
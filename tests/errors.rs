@@ -0,0 +1,144 @@
+use jdbc::errors::{Error, ErrorKind};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_syntax_error() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+
+    let err = conn.prepare_statement("selec 1").err().unwrap();
+    if let Error::Sql(err) = err {
+        assert_eq!(err.class_name, "org.sqlite.SQLiteException");
+        assert!(err.message.unwrap().contains("syntax error"));
+        assert_eq!(err.error_code, 1);
+    } else {
+        panic!("unexpected error: {}", err);
+    }
+
+    // The exception has been cleared, the connection is still usable.
+    let statement = conn.prepare_statement("select 1")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_int(1)?, Some(1));
+    Ok(())
+}
+
+#[test]
+fn test_constraint_violation() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+
+    conn.prepare_statement("create table test(id int primary key);")?
+        .execute_update()?;
+    let mut statement = conn.prepare_statement("insert into test(id) values(1);")?;
+    statement.execute_update()?;
+
+    let err = statement.execute_update().err().unwrap();
+    assert!(matches!(err, Error::Sql(_)));
+    assert!(err.to_string().contains("UNIQUE constraint failed"));
+    Ok(())
+}
+
+#[test]
+fn test_sqlite_kind() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+
+    let err = conn.prepare_statement("selec 1").err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::SyntaxError);
+
+    conn.prepare_statement("PRAGMA foreign_keys = ON;")?
+        .execute_update()?;
+    conn.prepare_statement("create table parent(id int primary key);")?
+        .execute_update()?;
+    conn.prepare_statement(
+        "create table child(id int primary key, parent_id int not null references parent(id));",
+    )?
+    .execute_update()?;
+    conn.prepare_statement("insert into parent(id) values(1);")?
+        .execute_update()?;
+
+    let err = conn
+        .prepare_statement("insert into parent(id) values(1);")?
+        .execute_update()
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), ErrorKind::UniqueViolation);
+
+    let err = conn
+        .prepare_statement("insert into child(id, parent_id) values(1, null);")?
+        .execute_update()
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), ErrorKind::NotNullViolation);
+
+    let err = conn
+        .prepare_statement("insert into child(id, parent_id) values(1, 2);")?
+        .execute_update()
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), ErrorKind::ForeignKeyViolation);
+    Ok(())
+}
+
+#[test]
+fn test_h2_kind() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+
+    let err = conn.prepare_statement("selec 1").err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::SyntaxError);
+
+    conn.prepare_statement("create table parent(id int primary key);")?
+        .execute_update()?;
+    conn.prepare_statement(
+        "create table child(id int primary key, parent_id int not null references parent(id));",
+    )?
+    .execute_update()?;
+    conn.prepare_statement("insert into parent(id) values(1);")?
+        .execute_update()?;
+
+    let err = conn
+        .prepare_statement("insert into parent(id) values(1);")?
+        .execute_update()
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), ErrorKind::UniqueViolation);
+
+    let err = conn
+        .prepare_statement("insert into child(id, parent_id) values(1, null);")?
+        .execute_update()
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), ErrorKind::NotNullViolation);
+
+    let err = conn
+        .prepare_statement("insert into child(id, parent_id) values(1, 2);")?
+        .execute_update()
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), ErrorKind::ForeignKeyViolation);
+    Ok(())
+}
+
+#[test]
+fn test_pool_timeout_kind() -> Result<(), Error> {
+    let ds = jdbc::Builder::new()
+        .vm(util::VM.clone())
+        .jdbc_url("jdbc:h2:mem:")
+        .property("maximumPoolSize", "1")
+        .property("connectionTimeout", "250")
+        .build()
+        .expect("init datasource error.");
+    let _conn = ds.get_connection()?;
+
+    let err = ds.get_connection().err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::Timeout);
+    if let Error::Sql(err) = err {
+        assert_eq!(err.class_name, "java.sql.SQLTransientConnectionException");
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use jdbc::errors::Error;
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_sqlite() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id integer primary key autoincrement, name text);")?
+        .execute_update()?;
+
+    for id in 1..4 {
+        let mut statement = conn
+            .prepare_statement_returning_keys("insert into test(name) values(?);")?
+            .set_string(1, "Tom")?;
+        assert_eq!(statement.execute_update()?, 1);
+        let keys = statement.generated_keys()?;
+        assert!(keys.next()?);
+        assert_eq!(keys.get_long(1)?, Some(id));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_h2_key_columns() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement(
+        "create table test(id bigint auto_increment primary key, name varchar(255));",
+    )?
+    .execute_update()?;
+
+    for id in 1..4 {
+        let mut statement = conn
+            .prepare_statement_with_key_columns("insert into test(name) values(?);", &["id"])?
+            .set_string(1, "Tom")?;
+        assert_eq!(statement.execute_update()?, 1);
+        let keys = statement.generated_keys()?;
+        assert!(keys.next()?);
+        assert_eq!(keys.get_long_by_label("id")?, Some(id));
+    }
+    Ok(())
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "serde_json")]
+
+use std::collections::BTreeMap;
+
+use jdbc::errors::Error;
+use serde_json::{json, Value};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_json() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    assert_eq!(conn.database_product_name()?, "H2");
+    conn.execute("create table doc(id int, body json)", ())?;
+
+    let body = json!({"title": "中文", "tags": ["a", "b"], "draft": false});
+    let mut scores = BTreeMap::new();
+    scores.insert("tom".to_string(), 3);
+    conn.prepare_statement("insert into doc(id, body) values(?, ?)")?
+        .set_int(1, 1)?
+        .set_json(2, &body)?
+        .add_batch()?
+        .set_int(1, 2)?
+        .set_json(2, &scores)?
+        .add_batch()?
+        .set_int(1, 3)?
+        .set_json::<Value>(2, None)?
+        .add_batch()?
+        .set_int(1, 4)?
+        .bind(2, &Value::Null)?
+        .add_batch()?
+        .execute_batch()?;
+
+    let statement = conn.prepare_statement("select body from doc order by id")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_json::<Value>(1)?, Some(body.clone()));
+    assert_eq!(result.get_json_by_label::<Value>("body")?, Some(body));
+    assert!(result.next()?);
+    assert_eq!(result.get_json(1)?, Some(scores));
+    assert!(result.next()?);
+    assert_eq!(result.get_json::<Value>(1)?, None);
+    assert!(result.next()?);
+    assert_eq!(result.get_json::<Value>(1)?, None);
+
+    let draft: Value = conn.query_scalar("select body from doc where id = ?", (1,))?;
+    assert_eq!(draft["draft"], json!(false));
+
+    // Through ToSql the value is stored as JSON too, not as a JSON string.
+    conn.execute(
+        "insert into doc(id, body) values(?, ?)",
+        (5, json!({"draft": true})),
+    )?;
+    let draft: Value = conn.query_scalar("select body from doc where id = ?", (5,))?;
+    assert_eq!(draft["draft"], json!(true));
+    Ok(())
+}
+
+#[test]
+fn test_json_text() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.execute("create table doc(body text)", ())?;
+
+    let tags = vec!["rust".to_string(), "jdbc".to_string()];
+    conn.prepare_statement("insert into doc(body) values(?)")?
+        .set_json(1, &tags)?
+        .execute_update()?;
+
+    let body: String = conn.query_scalar("select body from doc", ())?;
+    assert_eq!(body, r#"["rust","jdbc"]"#);
+    let statement = conn.prepare_statement("select body from doc")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_json::<Vec<String>>(1)?, Some(tags));
+    Ok(())
+}
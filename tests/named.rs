@@ -0,0 +1,81 @@
+use jdbc::{errors::Error, wrapper::sql::NamedSql};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_parse() {
+    let sql = NamedSql::parse("select * from user where id = :id and name = @name or id = :id");
+    assert_eq!(
+        sql.sql(),
+        "select * from user where id = ? and name = ? or id = ?"
+    );
+    assert_eq!(sql.names(), vec!["id", "name"]);
+    assert_eq!(sql.indices("id").unwrap(), vec![1, 3]);
+    assert_eq!(sql.indices("name").unwrap(), vec![2]);
+    assert!(matches!(
+        sql.indices("age"),
+        Err(Error::UnknownParameter(_))
+    ));
+}
+
+#[test]
+fn test_parse_skips_literals_and_comments() {
+    let sql = NamedSql::parse(
+        "select ':a', \"@b\", 'it''s :c', $$ :d $$, $tag$ :e $tag$ -- :f\n\
+         from t /* :g */ where x::int = :x and y = ? and z = @@version and w ?? 'k'",
+    );
+    assert_eq!(
+        sql.sql(),
+        "select ':a', \"@b\", 'it''s :c', $$ :d $$, $tag$ :e $tag$ -- :f\n\
+         from t /* :g */ where x::int = ? and y = ? and z = @@version and w ?? 'k'",
+    );
+    assert_eq!(sql.names(), vec!["x"]);
+    assert_eq!(sql.len(), 2);
+    assert_eq!(sql.indices("x").unwrap(), vec![1]);
+}
+
+#[test]
+fn test_parse_keeps_backslashes() {
+    let sql = NamedSql::parse(r"select * from file where path = 'C:\dir\' and id = :id");
+    assert_eq!(
+        sql.sql(),
+        r"select * from file where path = 'C:\dir\' and id = ?"
+    );
+    assert_eq!(sql.names(), vec!["id"]);
+}
+
+#[test]
+fn test_named_statement() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id int, name text);")?
+        .execute_update()?;
+
+    conn.prepare_named("insert into test(id, name) values(:id, :name)")?
+        .set_int_named("id", 1)?
+        .set_string_named("name", "a")?
+        .add_batch()?
+        .set_int_named("id", 2)?
+        .set_string_named("name", "b")?
+        .add_batch()?
+        .execute_batch()?;
+
+    let statement = conn
+        .prepare_named("select name from test where id = :id or (id + 1) = :id")?
+        .set_int_named("id", 2)?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_string(1)?, Some("a".to_string()));
+    assert!(result.next()?);
+    assert_eq!(result.get_string(1)?, Some("b".to_string()));
+    assert!(!result.next()?);
+
+    let statement = conn.prepare_named("select name from test where id = :id")?;
+    assert!(matches!(
+        statement.set_int_named("ids", 1),
+        Err(Error::UnknownParameter(name)) if name == "ids"
+    ));
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use jdbc::{errors::Error, wrapper::sql::SqlType};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_null() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(a int, b bigint, c text, d double, e boolean);")?
+        .execute_update()?;
+
+    conn.prepare_statement("insert into test(a, b, c, d, e) values(?, ?, ?, ?, ?)")?
+        .set_null(1, SqlType::Integer)?
+        .set_long(2, None)?
+        .set_string(3, None)?
+        .set_double(4, Some(1.5))?
+        .set_boolean(5, None)?
+        .execute_update()?;
+
+    let statement = conn.prepare_statement("select a, b, c, d, e from test")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_int(1)?, None);
+    assert_eq!(result.get_long(2)?, None);
+    assert_eq!(result.get_string(3)?, None);
+    assert_eq!(result.get_double(4)?, Some(1.5));
+    assert_eq!(result.get_boolean(5)?, None);
+    Ok(())
+}
+
+#[test]
+fn test_null_named() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    let statement = conn
+        .prepare_named("select cast(:value as int) is null, cast(:other as varchar) is null")?
+        .set_int_named("value", None)?
+        .set_null_named("other", SqlType::VarChar)?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_boolean(1)?, Some(true));
+    assert_eq!(result.get_boolean(2)?, Some(true));
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use jdbc::{errors::Error, params, ToSql};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_query() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.execute("create table user(id int, name text, age int);", ())?;
+
+    assert_eq!(
+        conn.execute("insert into user(id, name) values(?, ?)", (1, "Tom"))?,
+        1
+    );
+    let age: Option<i32> = Some(20);
+    conn.execute(
+        "insert into user(id, name, age) values(?, ?, ?)",
+        params![2, "Jerry", age],
+    )?;
+    let params: &[&dyn ToSql] = &[&3, &"Spike"];
+    conn.execute("insert into user(id, name) values(?, ?)", params)?;
+
+    let rows = conn.query("select id, name from user where id > ? order by id", (1,))?;
+    assert!(rows.next()?);
+    assert_eq!(rows.get::<i32, _>(1)?, 2);
+    assert!(rows.next()?);
+    assert_eq!(rows.get::<String, _>("name")?, "Spike");
+    assert!(!rows.next()?);
+
+    let (name, age): (String, Option<i32>) =
+        conn.query_one("select name, age from user where id = ?", (1,))?;
+    assert_eq!(name, "Tom");
+    assert_eq!(age, None);
+
+    let user: Option<(i32,)> = conn.query_opt("select id from user where id = ?", (9,))?;
+    assert_eq!(user, None);
+    assert!(matches!(
+        conn.query_one::<(i32,), _>("select id from user where id = ?", (9,)),
+        Err(Error::NoRows)
+    ));
+
+    let count: i64 = conn.query_scalar("select count(*) from user", params![])?;
+    assert_eq!(count, 3);
+    Ok(())
+}
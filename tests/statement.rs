@@ -0,0 +1,127 @@
+use jdbc::{
+    errors::Error,
+    wrapper::sql::{ResultSet, StatementResult},
+};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_execute() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    let statement = conn.create_statement()?;
+
+    assert!(!statement.execute("create table test(id int primary key);")?);
+    assert!(statement.get_result_set()?.is_none());
+
+    assert!(!statement.execute("insert into test(id) values(1),(2);")?);
+    assert_eq!(statement.get_update_count()?, Some(2));
+    assert!(!statement.get_more_results()?);
+    assert_eq!(statement.get_update_count()?, None);
+
+    assert!(statement.execute("select id from test order by id")?);
+    assert_eq!(statement.get_update_count()?, None);
+    let result = statement.get_result_set()?.unwrap();
+    assert!(result.next()?);
+    assert_eq!(result.get_int(1)?, Some(1));
+    Ok(())
+}
+
+#[test]
+fn test_execute_query_update() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    let statement = conn.create_statement()?;
+
+    statement.execute_update("create table test(id int primary key);")?;
+    assert_eq!(
+        statement.execute_update("insert into test(id) values(1),(2),(3);")?,
+        3
+    );
+    let result = statement.execute_query("select count(*) from test")?;
+    assert!(result.next()?);
+    assert_eq!(result.get_int(1)?, Some(3));
+    Ok(())
+}
+
+#[test]
+fn test_results() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    let statement = conn.create_statement()?;
+    statement.execute_update("create table test(id int primary key);")?;
+
+    let mut results = statement.execute_results("insert into test(id) values(1);")?;
+    assert!(matches!(
+        results.next(),
+        Some(Ok(StatementResult::UpdateCount(1)))
+    ));
+    assert!(results.next().is_none());
+
+    let mut rows = 0;
+    for result in statement.execute_results("select id from test")? {
+        match result? {
+            StatementResult::Rows(result) => {
+                while result.next()? {
+                    rows += 1;
+                }
+            }
+            StatementResult::UpdateCount(_) => panic!("unexpected update count"),
+        }
+    }
+    assert_eq!(rows, 1);
+    Ok(())
+}
+
+#[test]
+fn test_multiple_results() -> Result<(), Error> {
+    let ds = util::hsqldb();
+    let conn = ds.get_connection()?;
+    let statement = conn.create_statement()?;
+    statement.execute_update("create table test(id int primary key)")?;
+    statement.execute_update(
+        "create procedure two_results() reads sql data dynamic result sets 2 \
+         begin atomic \
+           declare r1 cursor with return for select id from test where id = 1; \
+           declare r2 cursor with return for select id from test where id > 1 order by id; \
+           open r1; \
+           open r2; \
+         end",
+    )?;
+
+    statement.execute_update("insert into test(id) values(1),(2),(3)")?;
+
+    let mut rows = Vec::new();
+    for result in statement.execute_results("call two_results()")? {
+        // HSQLDB may report an update count for the call itself before the cursors.
+        if let StatementResult::Rows(result) = result? {
+            rows.push(ids(&result)?);
+        }
+    }
+    assert_eq!(rows, vec![vec![1], vec![2, 3]]);
+
+    // The same sequence through a prepared statement.
+    let mut statement = conn.prepare_statement("call two_results()")?;
+    let mut is_result_set = statement.execute()?;
+    let mut rows = Vec::new();
+    loop {
+        if is_result_set {
+            rows.push(ids(&statement.get_result_set()?.unwrap())?);
+        } else if statement.get_update_count()?.is_none() {
+            break;
+        }
+        is_result_set = statement.get_more_results()?;
+    }
+    assert_eq!(rows, vec![vec![1], vec![2, 3]]);
+    Ok(())
+}
+
+fn ids(result: &ResultSet<'_>) -> Result<Vec<i32>, Error> {
+    let mut ids = Vec::new();
+    while result.next()? {
+        ids.extend(result.get_int(1)?);
+    }
+    Ok(ids)
+}
@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use jdbc::{errors::Error, wrapper::sql::FetchDirection, Builder, Connection};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+fn count_rows(conn: &Connection, sql: &str) -> Result<i32, Error> {
+    let statement = conn.prepare_statement(sql)?;
+    let result = statement.execute_query()?;
+    let mut rows = 0;
+    while result.next()? {
+        rows += 1;
+    }
+    Ok(rows)
+}
+
+#[test]
+fn test_statement() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id int primary key);")?
+        .execute_update()?;
+    conn.prepare_statement("insert into test(id) values(1),(2),(3);")?
+        .execute_update()?;
+
+    let statement = conn
+        .prepare_statement("select id from test")?
+        .set_query_timeout(Duration::from_millis(1500))?
+        .set_fetch_size(100)?
+        .set_fetch_direction(FetchDirection::Forward)?
+        .set_max_rows(2)?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert!(result.next()?);
+    assert!(!result.next()?);
+
+    let statement = conn
+        .create_statement()?
+        .set_query_timeout(Duration::from_secs(10))?
+        .set_max_rows(1)?;
+    let result = statement.execute_query("select id from test")?;
+    assert!(result.next()?);
+    assert!(!result.next()?);
+    Ok(())
+}
+
+#[test]
+fn test_builder_defaults() -> Result<(), Error> {
+    let ds = Builder::new()
+        .vm(util::VM.clone())
+        .jdbc_url("jdbc:sqlite::memory:")
+        .query_timeout(Duration::from_secs(30))
+        .fetch_size(100)
+        .max_rows(2)
+        .build()
+        .expect("init datasource error.");
+    let conn = ds.get_connection()?;
+    assert_eq!(conn.statement_options().max_rows, Some(2));
+
+    conn.prepare_statement("create table test(id int primary key);")?
+        .execute_update()?;
+    conn.prepare_statement("insert into test(id) values(1),(2),(3);")?
+        .execute_update()?;
+    assert_eq!(count_rows(&conn, "select id from test")?, 2);
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::io::{self, Cursor, Read, Write};
+
+use jdbc::errors::Error;
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_binary_stream() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute("create table file(id int, data blob)", ())?;
+
+    let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+    conn.prepare_statement("insert into file(id, data) values(?, ?)")?
+        .set_int(1, 1)?
+        .set_binary_stream(2, Cursor::new(data.clone()), data.len() as i64)?
+        .execute_update()?;
+
+    let statement = conn.prepare_statement("select data from file")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    let mut read = Vec::new();
+    result
+        .get_binary_stream(1)?
+        .expect("not null")
+        .read_to_end(&mut read)
+        .map_err(|e| Error::Conversion(e.into()))?;
+    assert_eq!(read, data);
+    Ok(())
+}
+
+#[test]
+fn test_character_stream() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute("create table doc(id int, body clob, note varchar(16))", ())?;
+
+    let text = "héllo wörld 😀 ".repeat(5_000);
+    conn.prepare_statement("insert into doc(id, body, note) values(?, ?, ?)")?
+        .set_int(1, 1)?
+        .set_character_stream(2, text.as_bytes())?
+        .set_ascii_stream(3, "ascii".as_bytes(), 5)?
+        .execute_update()?;
+
+    let statement = conn.prepare_statement("select body, note from doc")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    let mut read = String::new();
+    result
+        .get_character_stream_by_label("body")?
+        .expect("not null")
+        .read_to_string(&mut read)
+        .map_err(|e| Error::Conversion(e.into()))?;
+    assert_eq!(read, text);
+
+    let mut ascii = String::new();
+    result
+        .get_ascii_stream(2)?
+        .expect("not null")
+        .read_to_string(&mut ascii)
+        .map_err(|e| Error::Conversion(e.into()))?;
+    assert_eq!(ascii, "ascii");
+    Ok(())
+}
+
+#[test]
+fn test_lob_writer() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute("create table note(id int, data blob, body clob)", ())?;
+
+    let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+    let text = "héllo wörld 😀 ".repeat(5_000);
+    let mut blob = conn.create_blob()?;
+    let mut clob = conn.create_clob()?;
+    {
+        let mut writer = blob.binary_writer()?;
+        io::copy(&mut Cursor::new(&data), &mut writer).map_err(|e| Error::Conversion(e.into()))?;
+        writer.flush().map_err(|e| Error::Conversion(e.into()))?;
+    }
+    {
+        // Byte by byte splits the multi-byte characters across writes.
+        let mut writer = clob.character_writer()?;
+        for byte in &text.as_bytes()[..64] {
+            writer
+                .write_all(&[*byte])
+                .map_err(|e| Error::Conversion(e.into()))?;
+        }
+        writer
+            .write_all(&text.as_bytes()[64..])
+            .map_err(|e| Error::Conversion(e.into()))?;
+        assert!(writer.write(&[0xff]).is_err());
+        writer.flush().map_err(|e| Error::Conversion(e.into()))?;
+    }
+    conn.prepare_statement("insert into note(id, data, body) values(?, ?, ?)")?
+        .set_int(1, 1)?
+        .bind(2, &blob)?
+        .bind(3, &clob)?
+        .execute_update()?;
+
+    let (read, body): (Vec<u8>, String) =
+        conn.query_one("select data, body from note where id = ?", (1,))?;
+    assert_eq!(read, data);
+    assert_eq!(body, text);
+    Ok(())
+}
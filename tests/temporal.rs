@@ -0,0 +1,66 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use jdbc::errors::Error;
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_temporal() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute(
+        "create table event(id int, d date, t time(9), ts timestamp(9), tz timestamp(9) with time zone)",
+        (),
+    )?;
+
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    let time = NaiveTime::from_hms_nano_opt(23, 59, 58, 123_456_789).unwrap();
+    let date_time = date.and_time(time);
+    let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    let zoned = offset.from_local_datetime(&date_time).unwrap();
+
+    conn.prepare_statement("insert into event(id, d, t, ts, tz) values(?, ?, ?, ?, ?)")?
+        .set_int(1, 1)?
+        .set_naive_date(2, date)?
+        .set_naive_time(3, time)?
+        .set_naive_date_time(4, date_time)?
+        .set_offset_date_time(5, zoned)?
+        .add_batch()?
+        .set_int(1, 2)?
+        .set_naive_date(2, None)?
+        .set_naive_time(3, None)?
+        .set_naive_date_time(4, None)?
+        .set_offset_date_time(5, None)?
+        .add_batch()?
+        .execute_batch()?;
+
+    let statement = conn.prepare_statement("select d, t, ts, tz from event order by id")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_naive_date(1)?, Some(date));
+    assert_eq!(result.get_naive_time(2)?, Some(time));
+    assert_eq!(result.get_naive_date_time(3)?, Some(date_time));
+    let read = result.get_offset_date_time(4)?.unwrap();
+    assert_eq!(read, zoned);
+    assert_eq!(read.offset(), &offset);
+    assert_eq!(result.get_naive_date_by_label("d")?, Some(date));
+    assert_eq!(result.get_naive_time_by_label("t")?, Some(time));
+    assert_eq!(result.get_naive_date_time_by_label("ts")?, Some(date_time));
+    assert_eq!(result.get_offset_date_time_by_label("tz")?, Some(zoned));
+    assert!(result.next()?);
+    assert_eq!(result.get_naive_date(1)?, None);
+    assert_eq!(result.get_naive_time(2)?, None);
+    assert_eq!(result.get_naive_date_time(3)?, None);
+    assert_eq!(result.get_offset_date_time(4)?, None);
+
+    let (d, ts): (NaiveDate, NaiveDateTime) =
+        conn.query_one("select d, ts from event where id = ?", (1,))?;
+    assert_eq!((d, ts), (date, date_time));
+    let tz: Option<DateTime<FixedOffset>> =
+        conn.query_scalar("select tz from event where id = ?", (2,))?;
+    assert_eq!(tz, None);
+    Ok(())
+}
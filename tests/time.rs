@@ -0,0 +1,72 @@
+#![cfg(feature = "time")]
+
+use jdbc::errors::Error;
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_time() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute(
+        "create table moment(id int, d date, t time(9), ts timestamp(9), tz timestamp(9) with time zone)",
+        (),
+    )?;
+
+    let date = Date::from_calendar_date(2024, Month::February, 29).unwrap();
+    let time = Time::from_hms_nano(23, 59, 58, 123_456_789).unwrap();
+    let date_time = PrimitiveDateTime::new(date, time);
+    let offset = UtcOffset::from_hms(-3, -30, 0).unwrap();
+    let zoned = date_time.assume_offset(offset);
+
+    conn.prepare_statement("insert into moment(id, d, t, ts, tz) values(?, ?, ?, ?, ?)")?
+        .set_int(1, 1)?
+        .set_time_date(2, date)?
+        .set_time_time(3, time)?
+        .set_time_primitive_date_time(4, date_time)?
+        .set_time_offset_date_time(5, zoned)?
+        .add_batch()?
+        .set_int(1, 2)?
+        .set_time_date(2, None)?
+        .set_time_time(3, None)?
+        .set_time_primitive_date_time(4, None)?
+        .set_time_offset_date_time(5, None)?
+        .add_batch()?
+        .execute_batch()?;
+
+    let statement = conn.prepare_statement("select d, t, ts, tz from moment order by id")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_time_date(1)?, Some(date));
+    assert_eq!(result.get_time_time(2)?, Some(time));
+    assert_eq!(result.get_time_primitive_date_time(3)?, Some(date_time));
+    let read = result.get_time_offset_date_time(4)?.unwrap();
+    assert_eq!(read, zoned);
+    assert_eq!(read.offset(), offset);
+    assert_eq!(result.get_time_date_by_label("d")?, Some(date));
+    assert_eq!(result.get_time_time_by_label("t")?, Some(time));
+    assert_eq!(
+        result.get_time_primitive_date_time_by_label("ts")?,
+        Some(date_time)
+    );
+    assert_eq!(
+        result.get_time_offset_date_time_by_label("tz")?,
+        Some(zoned)
+    );
+    assert!(result.next()?);
+    assert_eq!(result.get_time_date(1)?, None);
+    assert_eq!(result.get_time_time(2)?, None);
+    assert_eq!(result.get_time_primitive_date_time(3)?, None);
+    assert_eq!(result.get_time_offset_date_time(4)?, None);
+
+    let (d, ts): (Date, PrimitiveDateTime) =
+        conn.query_one("select d, ts from moment where id = ?", (1,))?;
+    assert_eq!((d, ts), (date, date_time));
+    let tz: Option<OffsetDateTime> =
+        conn.query_scalar("select tz from moment where id = ?", (2,))?;
+    assert_eq!(tz, None);
+    Ok(())
+}
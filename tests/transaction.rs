@@ -0,0 +1,250 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use jdbc::{errors::Error, Connection, IsolationLevel};
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+fn count(conn: &Connection) -> Result<Option<i32>, Error> {
+    let statement = conn.prepare_statement("select count(*) from test")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    result.get_int(1)
+}
+
+fn insert(conn: &Connection, id: i32) -> Result<(), Error> {
+    conn.prepare_statement("insert into test(id) values(?);")?
+        .set_int(1, id)?
+        .execute_update()?;
+    Ok(())
+}
+
+#[test]
+fn test_guard() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id int primary key);")?
+        .execute_update()?;
+
+    // Rolled back on drop.
+    {
+        let tx = conn.transaction()?;
+        insert(&tx, 1)?;
+        assert_eq!(count(&tx)?, Some(1));
+    }
+    assert_eq!(count(&conn)?, Some(0));
+    assert!(conn.get_auto_commit()?);
+
+    let tx = conn.transaction()?;
+    assert!(!tx.get_auto_commit()?);
+    insert(&tx, 1)?;
+    tx.rollback()?;
+    assert_eq!(count(&conn)?, Some(0));
+
+    let tx = conn.transaction()?;
+    insert(&tx, 1)?;
+    tx.commit()?;
+    assert_eq!(count(&conn)?, Some(1));
+    assert!(conn.get_auto_commit()?);
+    Ok(())
+}
+
+#[test]
+fn test_closure() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id int primary key);")?
+        .execute_update()?;
+
+    conn.in_transaction(|tx| insert(tx, 1))?;
+    assert_eq!(count(&conn)?, Some(1));
+
+    // The second insert fails, the first one is rolled back.
+    let result = conn.in_transaction(|tx| {
+        insert(tx, 2)?;
+        insert(tx, 1)
+    });
+    assert!(result.is_err());
+    assert_eq!(count(&conn)?, Some(1));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        conn.in_transaction(|tx| -> Result<(), Error> {
+            insert(tx, 2)?;
+            panic!("abort");
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(count(&conn)?, Some(1));
+    assert!(conn.get_auto_commit()?);
+    Ok(())
+}
+
+#[test]
+fn test_manual() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id int primary key);")?
+        .execute_update()?;
+
+    conn.set_auto_commit(false)?;
+    insert(&conn, 1)?;
+    conn.rollback()?;
+    insert(&conn, 2)?;
+    conn.commit()?;
+    conn.set_auto_commit(true)?;
+    assert_eq!(count(&conn)?, Some(1));
+    Ok(())
+}
+
+#[test]
+fn test_nested() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id int primary key);")?
+        .execute_update()?;
+
+    let tx = conn.transaction()?;
+    assert!(!tx.is_nested());
+    insert(&tx, 1)?;
+    {
+        // Rolled back to the savepoint on drop.
+        let nested = tx.transaction()?;
+        assert!(nested.is_nested());
+        insert(&nested, 2)?;
+        assert_eq!(count(&nested)?, Some(2));
+    }
+    assert_eq!(count(&tx)?, Some(1));
+
+    let nested = tx.transaction()?;
+    insert(&nested, 3)?;
+    nested.commit()?;
+
+    // Functions composing their own transactions.
+    let result = tx.in_transaction(|nested| {
+        insert(nested, 4)?;
+        insert(nested, 1)
+    });
+    assert!(result.is_err());
+    assert_eq!(count(&tx)?, Some(2));
+    assert_eq!(tx.transaction_depth(), 1);
+    tx.commit()?;
+
+    assert_eq!(conn.transaction_depth(), 0);
+    assert_eq!(count(&conn)?, Some(2));
+    Ok(())
+}
+
+#[test]
+fn test_nested_rollback() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id int primary key);")?
+        .execute_update()?;
+
+    let tx = conn.transaction()?;
+    insert(&tx, 1)?;
+    // Each rolled back savepoint is released, they do not pile up.
+    for id in 2..100 {
+        let nested = tx.transaction()?;
+        insert(&nested, id)?;
+        nested.rollback()?;
+    }
+    assert_eq!(count(&tx)?, Some(1));
+    assert_eq!(tx.transaction_depth(), 1);
+    tx.commit()?;
+    assert_eq!(count(&conn)?, Some(1));
+    Ok(())
+}
+
+#[test]
+fn test_savepoint() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.prepare_statement("create table test(id int primary key);")?
+        .execute_update()?;
+
+    conn.set_auto_commit(false)?;
+    insert(&conn, 1)?;
+    let savepoint = conn.set_savepoint_named("first")?;
+    insert(&conn, 2)?;
+    conn.rollback_to_savepoint(&savepoint)?;
+    conn.release_savepoint(savepoint)?;
+    conn.commit()?;
+    conn.set_auto_commit(true)?;
+    assert_eq!(count(&conn)?, Some(1));
+    Ok(())
+}
+
+#[test]
+fn test_isolation_level() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+
+    conn.set_transaction_isolation(IsolationLevel::Serializable)?;
+    assert_eq!(
+        conn.get_transaction_isolation()?,
+        Some(IsolationLevel::Serializable)
+    );
+    conn.set_transaction_isolation(IsolationLevel::ReadCommitted)?;
+
+    conn.set_read_only(false)?;
+    assert!(!conn.is_read_only()?);
+
+    let tx = conn
+        .transaction_builder()
+        .isolation_level(IsolationLevel::RepeatableRead)
+        .read_only(true)
+        .begin()?;
+    assert_eq!(
+        tx.get_transaction_isolation()?,
+        Some(IsolationLevel::RepeatableRead)
+    );
+    assert!(tx.is_read_only()?);
+    tx.commit()?;
+    // Restored after the transaction.
+    assert_eq!(
+        conn.get_transaction_isolation()?,
+        Some(IsolationLevel::ReadCommitted)
+    );
+    assert!(!conn.is_read_only()?);
+
+    let level = conn
+        .transaction_builder()
+        .isolation_level(IsolationLevel::Serializable)
+        .in_transaction(|tx| tx.get_transaction_isolation())?;
+    assert_eq!(level, Some(IsolationLevel::Serializable));
+    assert_eq!(
+        conn.get_transaction_isolation()?,
+        Some(IsolationLevel::ReadCommitted)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_isolation_level_rollback() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    // The driver default, never set explicitly.
+    let default = conn.get_transaction_isolation()?;
+
+    let tx = conn
+        .transaction_builder()
+        .isolation_level(IsolationLevel::Serializable)
+        .read_only(true)
+        .begin()?;
+    tx.rollback()?;
+    assert_eq!(conn.get_transaction_isolation()?, default);
+    assert!(!conn.is_read_only()?);
+
+    {
+        let _tx = conn
+            .transaction_builder()
+            .isolation_level(IsolationLevel::Serializable)
+            .read_only(true)
+            .begin()?;
+    }
+    assert_eq!(conn.get_transaction_isolation()?, default);
+    assert!(!conn.is_read_only()?);
+    Ok(())
+}
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 
 use jdbc::JvmBuilder;
 use jdbc::{Builder, Datasource};
@@ -13,6 +16,28 @@ pub fn sqlite() -> Datasource {
         .expect("init datasource error.")
 }
 
+#[allow(dead_code)]
+pub fn h2() -> Datasource {
+    Builder::new()
+        .vm(VM.clone())
+        .jdbc_url("jdbc:h2:mem:")
+        .build()
+        .expect("init datasource error.")
+}
+
+/// A private in-memory database, e.g. for procedures returning several result sets.
+#[allow(dead_code)]
+pub fn hsqldb() -> Datasource {
+    static DATABASES: AtomicUsize = AtomicUsize::new(0);
+    let id = DATABASES.fetch_add(1, Ordering::Relaxed);
+    Builder::new()
+        .vm(VM.clone())
+        .jdbc_url(&format!("jdbc:hsqldb:mem:test{id}"))
+        .username("SA")
+        .build()
+        .expect("init datasource error.")
+}
+
 #[allow(dead_code)]
 pub fn vm() -> JavaVM {
     let libs = concat!(env!("OUT_DIR"), "/libs");
@@ -27,5 +52,5 @@ pub fn vm() -> JavaVM {
 }
 
 lazy_static! {
-    static ref VM: Arc<JavaVM> = Arc::new(vm());
+    pub static ref VM: Arc<JavaVM> = Arc::new(vm());
 }
@@ -0,0 +1,65 @@
+#![cfg(feature = "uuid")]
+
+use jdbc::errors::Error;
+use uuid::Uuid;
+
+#[macro_use]
+extern crate lazy_static;
+mod util;
+
+#[test]
+fn test_uuid() -> Result<(), Error> {
+    let ds = util::h2();
+    let conn = ds.get_connection()?;
+    conn.execute("create table account(id uuid, name varchar(32))", ())?;
+
+    let id = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6").unwrap();
+    conn.prepare_statement("insert into account(id, name) values(?, ?)")?
+        .set_uuid(1, id)?
+        .set_string(2, "Tom")?
+        .add_batch()?
+        .set_uuid(1, None)?
+        .set_string(2, "Jerry")?
+        .add_batch()?
+        .execute_batch()?;
+
+    let statement = conn.prepare_statement("select id from account order by name desc")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_uuid(1)?, Some(id));
+    assert_eq!(result.get_uuid_by_label("id")?, Some(id));
+    assert!(result.next()?);
+    assert_eq!(result.get_uuid(1)?, None);
+
+    // Past the last row getObject(1, UUID.class) fails with "No data is available",
+    // which is returned rather than retried as a plain getObject.
+    assert!(!result.next()?);
+    assert!(matches!(
+        result.get_uuid(1),
+        Err(Error::Sql(err)) if err.sql_state.as_deref() == Some("02000")
+    ));
+
+    let name: String = conn.query_scalar("select name from account where id = ?", (id,))?;
+    assert_eq!(name, "Tom");
+    Ok(())
+}
+
+#[test]
+fn test_uuid_fallback() -> Result<(), Error> {
+    let ds = util::sqlite();
+    let conn = ds.get_connection()?;
+    conn.execute("create table account(text_id text, binary_id blob)", ())?;
+
+    let id = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6").unwrap();
+    conn.prepare_statement("insert into account(text_id, binary_id) values(?, ?)")?
+        .set_string(1, &id.to_string())?
+        .set_bytes(2, id.as_bytes().as_slice())?
+        .execute_update()?;
+
+    let statement = conn.prepare_statement("select text_id, binary_id from account")?;
+    let result = statement.execute_query()?;
+    assert!(result.next()?);
+    assert_eq!(result.get_uuid(1)?, Some(id));
+    assert_eq!(result.get_uuid_by_label("binary_id")?, Some(id));
+    Ok(())
+}